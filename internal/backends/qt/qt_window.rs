@@ -518,9 +518,54 @@ fn adjust_rect_and_border_for_inner_drawing(rect: &mut qttypes::QRectF, border_w
     rect.height -= *border_width as f64;
 }
 
+/// Converts the events of a Path item to a QPainterPath, in logical coordinates. Returns None if
+/// the path can't be rendered.
+fn to_painter_path(
+    path: Pin<&items::Path>,
+    item_rc: &ItemRc,
+) -> Option<(LogicalVector, QPainterPath)> {
+    let (offset, path_events) = path.fitted_path_events(item_rc)?;
+    let mut painter_path = QPainterPath::default();
+
+    painter_path.set_fill_rule(match path.fill_rule() {
+        FillRule::Nonzero => key_generated::Qt_FillRule_WindingFill,
+        FillRule::Evenodd => key_generated::Qt_FillRule_OddEvenFill,
+    });
+
+    for x in path_events.iter() {
+        fn to_qpointf(p: Point) -> qttypes::QPointF {
+            qttypes::QPointF { x: p.x as _, y: p.y as _ }
+        }
+        match x {
+            lyon_path::Event::Begin { at } => {
+                painter_path.move_to(to_qpointf(at));
+            }
+            lyon_path::Event::Line { from: _, to } => {
+                painter_path.line_to(to_qpointf(to));
+            }
+            lyon_path::Event::Quadratic { from: _, ctrl, to } => {
+                painter_path.quad_to(to_qpointf(ctrl), to_qpointf(to));
+            }
+
+            lyon_path::Event::Cubic { from: _, ctrl1, ctrl2, to } => {
+                painter_path.cubic_to(to_qpointf(ctrl1), to_qpointf(ctrl2), to_qpointf(to));
+            }
+            lyon_path::Event::End { last: _, first: _, close } => {
+                // FIXME: are we supposed to do something with last and first?
+                if close {
+                    painter_path.close()
+                }
+            }
+        }
+    }
+
+    Some((offset, painter_path))
+}
+
 struct QtItemRenderer<'a> {
     painter: QPainterPtr,
     cache: &'a ItemCache<qttypes::QPixmap>,
+    path_cache: &'a ItemCache<Option<(LogicalVector, QPainterPath)>>,
     window: &'a i_slint_core::api::Window,
     metrics: RenderingMetrics,
 }
@@ -797,8 +842,11 @@ fn draw_text_input(
     }
 
     fn draw_path(&mut self, path: Pin<&items::Path>, item_rc: &ItemRc, size: LogicalSize) {
-        let (offset, path_events) = match path.fitted_path_events(item_rc) {
-            Some(offset_and_events) => offset_and_events,
+        let (offset, mut painter_path) = match self
+            .path_cache
+            .get_or_update_cache_entry(item_rc, || to_painter_path(path, item_rc))
+        {
+            Some(offset_and_path) => offset_and_path,
             None => return,
         };
         let rect: qttypes::QRectF = check_geometry!(size);
@@ -806,39 +854,6 @@ fn draw_path(&mut self, path: Pin<&items::Path>, item_rc: &ItemRc, size: Logical
         let stroke_brush: qttypes::QBrush = into_qbrush(path.stroke(), rect.width, rect.height);
         let stroke_width: f32 = path.stroke_width().get();
         let pos = qttypes::QPoint { x: offset.x as _, y: offset.y as _ };
-        let mut painter_path = QPainterPath::default();
-
-        painter_path.set_fill_rule(match path.fill_rule() {
-            FillRule::Nonzero => key_generated::Qt_FillRule_WindingFill,
-            FillRule::Evenodd => key_generated::Qt_FillRule_OddEvenFill,
-        });
-
-        for x in path_events.iter() {
-            fn to_qpointf(p: Point) -> qttypes::QPointF {
-                qttypes::QPointF { x: p.x as _, y: p.y as _ }
-            }
-            match x {
-                lyon_path::Event::Begin { at } => {
-                    painter_path.move_to(to_qpointf(at));
-                }
-                lyon_path::Event::Line { from: _, to } => {
-                    painter_path.line_to(to_qpointf(to));
-                }
-                lyon_path::Event::Quadratic { from: _, ctrl, to } => {
-                    painter_path.quad_to(to_qpointf(ctrl), to_qpointf(to));
-                }
-
-                lyon_path::Event::Cubic { from: _, ctrl1, ctrl2, to } => {
-                    painter_path.cubic_to(to_qpointf(ctrl1), to_qpointf(ctrl2), to_qpointf(to));
-                }
-                lyon_path::Event::End { last: _, first: _, close } => {
-                    // FIXME: are we supposed to do something with last and first?
-                    if close {
-                        painter_path.close()
-                    }
-                }
-            }
-        }
 
         let painter: &mut QPainterPtr = &mut self.painter;
         cpp! { unsafe [
@@ -1398,6 +1413,7 @@ pub struct QtWindow {
     rendering_metrics_collector: RefCell<Option<Rc<RenderingMetricsCollector>>>,
 
     cache: ItemCache<qttypes::QPixmap>,
+    path_cache: ItemCache<Option<(LogicalVector, QPainterPath)>>,
 
     tree_structure_changed: RefCell<bool>,
 
@@ -1424,6 +1440,7 @@ pub fn new() -> Rc<Self> {
                 self_weak: self_weak.clone(),
                 rendering_metrics_collector: Default::default(),
                 cache: Default::default(),
+                path_cache: Default::default(),
                 tree_structure_changed: RefCell::new(false),
                 dark_color_scheme: Default::default(),
             }
@@ -1449,6 +1466,7 @@ fn paint_event(&self, painter: QPainterPtr) {
             let mut renderer = QtItemRenderer {
                 painter,
                 cache: &self.cache,
+                path_cache: &self.path_cache,
                 window: &self.window,
                 metrics: RenderingMetrics { layers_created: Some(0) },
             };
@@ -2033,6 +2051,7 @@ fn free_graphics_resources(
     ) -> Result<(), i_slint_core::platform::PlatformError> {
         // Invalidate caches:
         self.cache.component_destroyed(component);
+        self.path_cache.component_destroyed(component);
         Ok(())
     }
 
@@ -0,0 +1,153 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! This module provides matching of keyboard shortcuts against the key events of the event loop.
+
+use winit::keyboard::{Key, ModifiersState};
+
+/// A keyboard shortcut, such as Ctrl+Shift+S, that can be matched against the events passed to the
+/// callback set with [`crate::WinitWindowAccessor::on_keyboard_event`].
+///
+/// Use [`Self::with_primary_modifier()`] for shortcuts that use the Command key on macOS and the
+/// Control key on other platforms:
+///
+/// ```rust,no_run
+/// use i_slint_backend_winit::{Accelerator, WinitWindowAccessor};
+/// use i_slint_backend_winit::winit::keyboard::Key;
+/// # let window: slint::Window = todo!();
+/// let save = Accelerator::new(Key::Character("s".into())).with_primary_modifier();
+/// window.on_keyboard_event(move |event| {
+///     if save.matches(event) {
+///         // save the document
+///     }
+/// });
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Accelerator {
+    key: Key,
+    modifiers: ModifiersState,
+}
+
+impl Accelerator {
+    /// Creates a shortcut for the specified key without modifiers. Characters are matched without
+    /// regard to case, so `Key::Character("s".into())` also matches when Shift produces "S".
+    ///
+    /// Specify the key as produced without modifiers, as it is reported in
+    /// [`crate::KeyboardEvent::logical_key`]: for Ctrl+Shift+1, use `Key::Character("1".into())`
+    /// with `ModifiersState::SHIFT`, not "!". On wasm32, Android and iOS, where the event's key
+    /// includes the effect of Shift, such shortcuts only match with the shifted character.
+    pub fn new(key: Key) -> Self {
+        Self { key: normalize_key(key), modifiers: ModifiersState::empty() }
+    }
+
+    /// Adds the primary modifier of the platform: the Command key on macOS and the Control key
+    /// on other platforms.
+    pub fn with_primary_modifier(mut self) -> Self {
+        self.modifiers |=
+            if cfg!(target_os = "macos") { ModifiersState::SUPER } else { ModifiersState::CONTROL };
+        self
+    }
+
+    /// Adds the specified modifiers, such as `ModifiersState::SHIFT`. Prefer
+    /// [`Self::with_primary_modifier()`] over `ModifiersState::CONTROL` for shortcuts that should
+    /// follow the platform's conventions.
+    pub fn with_modifiers(mut self, modifiers: ModifiersState) -> Self {
+        self.modifiers |= modifiers;
+        self
+    }
+
+    /// Returns true if the event is the initial press of the shortcut's key while exactly the
+    /// shortcut's modifiers are held down. Repeated presses of a held down key, key releases,
+    /// and key presses that are part of an input method composition don't match. The key is compared
+    /// with the event's key without modifiers, so Ctrl+Shift+1 matches although Shift turns "1" into "!".
+    pub fn matches(&self, event: &crate::KeyboardEvent) -> bool {
+        event.pressed
+            && !event.repeat
+            && !event.composing
+            && event.modifiers == self.modifiers
+            && normalize_key(event.logical_key.clone()) == self.key
+    }
+}
+
+fn normalize_key(key: Key) -> Key {
+    match key {
+        Key::Character(text) => Key::Character(text.to_lowercase().into()),
+        key => key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winit::keyboard::{KeyCode, NamedKey, PhysicalKey};
+
+    fn press(logical_key: Key, modifiers: ModifiersState) -> crate::KeyboardEvent {
+        crate::KeyboardEvent {
+            physical_key: PhysicalKey::Code(KeyCode::KeyS),
+            logical_key,
+            text: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+            composing: false,
+        }
+    }
+
+    #[test]
+    fn case_folding() {
+        let accelerator = Accelerator::new(Key::Character("S".into()));
+        assert!(accelerator.matches(&press(Key::Character("s".into()), ModifiersState::empty())));
+        assert!(accelerator.matches(&press(Key::Character("S".into()), ModifiersState::empty())));
+        assert!(!accelerator.matches(&press(Key::Character("d".into()), ModifiersState::empty())));
+
+        let accelerator = Accelerator::new(Key::Character("s".into()))
+            .with_modifiers(ModifiersState::CONTROL | ModifiersState::SHIFT);
+        assert!(accelerator.matches(&press(
+            Key::Character("S".into()),
+            ModifiersState::CONTROL | ModifiersState::SHIFT
+        )));
+        assert!(!accelerator.matches(&press(Key::Character("s".into()), ModifiersState::CONTROL)));
+    }
+
+    #[test]
+    fn shift_digit() {
+        let accelerator =
+            Accelerator::new(Key::Character("1".into())).with_modifiers(ModifiersState::SHIFT);
+        assert!(accelerator.matches(&press(Key::Character("1".into()), ModifiersState::SHIFT)));
+        assert!(!accelerator.matches(&press(Key::Character("1".into()), ModifiersState::empty())));
+        assert!(!accelerator.matches(&press(Key::Character("!".into()), ModifiersState::SHIFT)));
+    }
+
+    #[test]
+    fn modifiers_must_match_exactly() {
+        let accelerator = Accelerator::new(Key::Named(NamedKey::F5));
+        assert!(accelerator.matches(&press(Key::Named(NamedKey::F5), ModifiersState::empty())));
+        assert!(!accelerator.matches(&press(Key::Named(NamedKey::F5), ModifiersState::ALT)));
+    }
+
+    #[test]
+    fn only_initial_presses_match() {
+        let accelerator = Accelerator::new(Key::Character("s".into()));
+        let event = press(Key::Character("s".into()), ModifiersState::empty());
+        assert!(accelerator.matches(&event));
+        assert!(!accelerator.matches(&crate::KeyboardEvent { repeat: true, ..event.clone() }));
+        assert!(!accelerator.matches(&crate::KeyboardEvent { pressed: false, ..event.clone() }));
+        assert!(!accelerator.matches(&crate::KeyboardEvent { composing: true, ..event }));
+    }
+
+    #[test]
+    fn primary_modifier() {
+        let accelerator = Accelerator::new(Key::Character("s".into())).with_primary_modifier();
+        let (primary, other) = if cfg!(target_os = "macos") {
+            (ModifiersState::SUPER, ModifiersState::CONTROL)
+        } else {
+            (ModifiersState::CONTROL, ModifiersState::SUPER)
+        };
+        assert!(accelerator.matches(&press(Key::Character("s".into()), primary)));
+        assert!(!accelerator.matches(&press(Key::Character("s".into()), other)));
+        assert_eq!(
+            accelerator,
+            Accelerator::new(Key::Character("s".into())).with_modifiers(primary)
+        );
+    }
+}
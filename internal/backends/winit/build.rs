@@ -8,5 +8,6 @@ fn main() {
     cfg_aliases! {
        enable_skia_renderer: { any(feature = "renderer-skia", feature = "renderer-skia-opengl", feature = "renderer-skia-vulkan")},
        enable_accesskit: { all(feature = "accessibility", not(target_arch = "wasm32")) },
+       enable_primary_selection: { all(any(feature = "wayland", feature = "x11"), unix, not(any(target_os = "macos", target_os = "android", target_os = "ios", target_os = "emscripten"))) },
     }
 }
@@ -131,6 +131,9 @@ fn clipboard(
 thread_local! {
     static ALL_WINDOWS: RefCell<std::collections::HashMap<winit::window::WindowId, Weak<WinitWindowAdapter>>> = RefCell::new(std::collections::HashMap::new());
     static MAYBE_LOOP_INSTANCE: RefCell<Option<NotRunningEventLoop>> = RefCell::default();
+    static MIN_FRAME_INTERVAL: std::cell::Cell<Option<core::time::Duration>> = Default::default();
+    static REQUESTED_OPENGL_API: std::cell::Cell<Option<crate::RequestedOpenGLApi>> = Default::default();
+    static SWAP_INTERVAL: std::cell::Cell<crate::SwapInterval> = Default::default();
 }
 
 scoped_tls_hkt::scoped_thread_local!(static CURRENT_WINDOW_TARGET : for<'a> &'a RunningEventLoop<'a>);
@@ -215,6 +218,34 @@ pub fn window_by_id(id: winit::window::WindowId) -> Option<Rc<WinitWindowAdapter
     ALL_WINDOWS.with(|windows| windows.borrow().get(&id).and_then(|weakref| weakref.upgrade()))
 }
 
+/// Sets the minimum time between two frames rendered for the same window. See [`crate::Backend::set_frame_interval`].
+pub(crate) fn set_min_frame_interval(interval: Option<core::time::Duration>) {
+    MIN_FRAME_INTERVAL.with(|cell| cell.set(interval.filter(|interval| !interval.is_zero())))
+}
+
+pub(crate) fn min_frame_interval() -> Option<core::time::Duration> {
+    MIN_FRAME_INTERVAL.with(|cell| cell.get())
+}
+
+/// Sets the OpenGL API to request for new windows. See [`crate::Backend::set_requested_opengl_api`].
+pub(crate) fn set_requested_opengl_api(api: Option<crate::RequestedOpenGLApi>) {
+    REQUESTED_OPENGL_API.with(|cell| cell.set(api))
+}
+
+#[allow(unused)]
+pub(crate) fn requested_opengl_api() -> Option<crate::RequestedOpenGLApi> {
+    REQUESTED_OPENGL_API.with(|cell| cell.get())
+}
+
+pub(crate) fn set_swap_interval(swap_interval: crate::SwapInterval) {
+    SWAP_INTERVAL.with(|cell| cell.set(swap_interval))
+}
+
+#[allow(unused)]
+pub(crate) fn swap_interval() -> crate::SwapInterval {
+    SWAP_INTERVAL.with(|cell| cell.get())
+}
+
 /// This enum captures run-time specific events that can be dispatched to the event loop in
 /// addition to the winit events.
 pub enum CustomEvent {
@@ -243,17 +274,29 @@ pub struct EventLoopState {
     // last seen cursor position
     cursor_pos: LogicalPoint,
     pressed: bool,
+    // last seen state of the modifier keys, to tell Tab from Shift+Tab
+    modifiers: winit::keyboard::ModifiersState,
+    // true while the input method shows pre-edit text
+    ime_composing: bool,
 
     loop_error: Option<PlatformError>,
 }
 
 impl EventLoopState {
+    #[allow(clippy::unnecessary_cast)] // Coord is used!
+    fn cursor_logical_position(&self) -> corelib::api::LogicalPosition {
+        corelib::api::LogicalPosition::new(self.cursor_pos.x as f32, self.cursor_pos.y as f32)
+    }
+
     fn process_window_event(&mut self, window: Rc<WinitWindowAdapter>, event: WindowEvent) {
         let runtime_window = WindowInner::from_pub(window.window());
         match event {
             WindowEvent::RedrawRequested => self.loop_error = window.draw().err(),
             WindowEvent::Resized(size) => {
-                self.loop_error = window.resize_event(size).err();
+                self.loop_error = window
+                    .resize_event(size)
+                    .and_then(|_| window.restore_windowed_geometry())
+                    .err();
             }
             WindowEvent::CloseRequested => {
                 window.window().dispatch_event(corelib::platform::WindowEvent::CloseRequested);
@@ -266,10 +309,58 @@ fn process_window_event(&mut self, window: Rc<WinitWindowAdapter>, event: Window
                     window.window().dispatch_event(
                         corelib::platform::WindowEvent::WindowActiveChanged(have_focus),
                     );
+                    window.focus_changed.call(&have_focus);
+                }
+                if !have_focus {
+                    // The release of a button pressed before the focus moved elsewhere may never
+                    // arrive, so cancel the press instead of turning a later release into a click.
+                    // This also resets the hover state until the mouse moves again.
+                    self.pressed = false;
+                    runtime_window.process_mouse_input(MouseEvent::Exit);
                 }
             }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers.state();
+            }
 
             WindowEvent::KeyboardInput { event, .. } => {
+                // winit's `logical_key` includes the effect of all modifiers except Control, so
+                // Shift+1 is reported as "!". Report the key without modifiers where winit can.
+                #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+                let logical_key = {
+                    use winit::platform::modifier_supplement::KeyEventExtModifierSupplement;
+                    event.key_without_modifiers()
+                };
+                #[cfg(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))]
+                let logical_key = event.logical_key.clone();
+
+                window.keyboard_event.call(&crate::KeyboardEvent {
+                    physical_key: event.physical_key,
+                    logical_key,
+                    text: match event.logical_key {
+                        winit::keyboard::Key::Dead(_) => None,
+                        _ => event.text.as_ref().map(|text| text.as_str().into()),
+                    },
+                    pressed: event.state == winit::event::ElementState::Pressed,
+                    repeat: event.repeat,
+                    modifiers: self.modifiers,
+                    composing: self.ime_composing,
+                });
+
+                if event.state == winit::event::ElementState::Pressed
+                    && event.logical_key
+                        == winit::keyboard::Key::Named(winit::keyboard::NamedKey::Tab)
+                {
+                    let traversal = if self.modifiers.shift_key() {
+                        crate::FocusTraversal::Previous
+                    } else {
+                        crate::FocusTraversal::Next
+                    };
+                    if window.focus_traversal.call(&traversal) {
+                        return;
+                    }
+                }
+
                 let key_code = event.logical_key;
                 // For now: Match Qt's behavior of mapping command to control and control to meta (LWin/RWin).
                 #[cfg(target_os = "macos")]
@@ -313,6 +404,7 @@ macro_rules! winit_key_to_char {
                 });
             }
             WindowEvent::Ime(winit::event::Ime::Preedit(string, preedit_selection)) => {
+                self.ime_composing = !string.is_empty();
                 let event = KeyEvent {
                     event_type: KeyEventType::UpdateComposition,
                     preedit_text: string.into(),
@@ -321,7 +413,15 @@ macro_rules! winit_key_to_char {
                 };
                 runtime_window.process_key_input(event);
             }
+            WindowEvent::Ime(winit::event::Ime::Disabled) => {
+                self.ime_composing = false;
+                // The input method may be switched off while composing, so drop any pending pre-edit text.
+                let event =
+                    KeyEvent { event_type: KeyEventType::UpdateComposition, ..Default::default() };
+                runtime_window.process_key_input(event);
+            }
             WindowEvent::Ime(winit::event::Ime::Commit(string)) => {
+                self.ime_composing = false;
                 let event = KeyEvent {
                     event_type: KeyEventType::CommitComposition,
                     text: string.into(),
@@ -332,9 +432,11 @@ macro_rules! winit_key_to_char {
             WindowEvent::CursorMoved { position, .. } => {
                 let position = position.to_logical(runtime_window.scale_factor() as f64);
                 self.cursor_pos = euclid::point2(position.x, position.y);
+                window.cursor_position.set(Some(self.cursor_logical_position()));
                 runtime_window.process_mouse_input(MouseEvent::Moved { position: self.cursor_pos });
             }
             WindowEvent::CursorLeft { .. } => {
+                window.cursor_position.set(None);
                 // On the html canvas, we don't get the mouse move or release event when outside the canvas. So we have no choice but canceling the event
                 if cfg!(target_arch = "wasm32") || !self.pressed {
                     self.pressed = false;
@@ -342,13 +444,23 @@ macro_rules! winit_key_to_char {
                 }
             }
             WindowEvent::MouseWheel { delta, .. } => {
-                let (delta_x, delta_y) = match delta {
-                    winit::event::MouseScrollDelta::LineDelta(lx, ly) => (lx * 60., ly * 60.),
+                // Pixel deltas are physical pixels, so convert them with Slint's scale factor only once.
+                // The sign is passed through unchanged, as it already reflects the natural scrolling setting.
+                let (delta_x, delta_y, precise) = match delta {
+                    winit::event::MouseScrollDelta::LineDelta(lx, ly) => {
+                        (lx * 60., ly * 60., false)
+                    }
                     winit::event::MouseScrollDelta::PixelDelta(d) => {
                         let d = d.to_logical(runtime_window.scale_factor() as f64);
-                        (d.x, d.y)
+                        (d.x, d.y, true)
                     }
                 };
+                window.scroll_event.call(&crate::ScrollEvent {
+                    delta_x,
+                    delta_y,
+                    precise,
+                    position: self.cursor_logical_position(),
+                });
                 runtime_window.process_mouse_input(MouseEvent::Wheel {
                     position: self.cursor_pos,
                     delta_x,
@@ -376,8 +488,26 @@ macro_rules! winit_key_to_char {
                 };
                 runtime_window.process_mouse_input(ev);
             }
+            WindowEvent::TouchpadMagnify { delta, .. } => {
+                window.touch_event.call(&crate::TouchEvent::Pinch {
+                    center: self.cursor_logical_position(),
+                    scale: 1. + delta as f32,
+                });
+            }
             WindowEvent::Touch(touch) => {
                 let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
+                let (is_primary, touch_events) = window.touch_state.borrow_mut().process(
+                    touch.id,
+                    touch.phase,
+                    corelib::api::LogicalPosition::new(location.x, location.y),
+                );
+                for touch_event in touch_events {
+                    window.touch_event.call(&touch_event);
+                }
+                // Only the first finger is used to emulate the mouse
+                if !is_primary {
+                    return;
+                }
                 let position = euclid::point2(location.x, location.y);
                 let ev = match touch.phase {
                     winit::event::TouchPhase::Started => {
@@ -402,20 +532,34 @@ macro_rules! winit_key_to_char {
             }
             WindowEvent::ScaleFactorChanged { scale_factor, inner_size_writer: _ } => {
                 if std::env::var("SLINT_SCALE_FACTOR").is_err() {
+                    let old_scale_factor = runtime_window.scale_factor();
+                    let scale_factor = scale_factor as f32;
                     window.window().dispatch_event(
-                        corelib::platform::WindowEvent::ScaleFactorChanged {
-                            scale_factor: scale_factor as f32,
-                        },
+                        corelib::platform::WindowEvent::ScaleFactorChanged { scale_factor },
                     );
+                    // winit keeps the logical size of the window the same, so derive the new physical size from that.
+                    let new_size =
+                        window.size().to_logical(old_scale_factor).to_physical(scale_factor);
+                    window.scale_factor_changed.call(&(old_scale_factor, scale_factor, new_size));
                     // TODO: send a resize event or try to keep the logical size the same.
                     //window.resize_event(inner_size_writer.???)?;
                 }
+                window.check_monitor_changed();
+            }
+            WindowEvent::Moved(_) => {
+                self.loop_error = window.restore_windowed_geometry().err();
+                window.check_monitor_changed();
             }
             WindowEvent::ThemeChanged(theme) => {
                 window.set_dark_color_scheme(theme == winit::window::Theme::Dark)
             }
             WindowEvent::Occluded(x) => {
-                window.renderer.occluded(x);
+                window.set_occluded(x);
+            }
+            WindowEvent::HoveredFile(path) => window.file_hovered(path),
+            WindowEvent::DroppedFile(path) => window.file_dropped(path),
+            WindowEvent::HoveredFileCancelled => {
+                window.file_hover_cancelled(self.cursor_logical_position())
             }
             _ => {}
         }
@@ -471,20 +615,33 @@ fn process_event(
             }),
 
             Event::AboutToWait => {
+                // The earliest time at which a redraw that was throttled by the frame interval is due.
+                let mut next_deferred_redraw: Option<core::time::Duration> = None;
+
                 if !event_loop_target.exiting() {
+                    let now = corelib::animations::Instant::now();
                     ALL_WINDOWS.with(|windows| {
                         for w in windows.borrow().iter().filter_map(|(_, w)| w.upgrade()) {
+                            w.dispatch_pending_file_drop_events(self.cursor_logical_position());
                             if w.window().has_active_animations() {
                                 w.request_redraw();
                             }
+                            if let Some(due) = w.flush_deferred_redraw(now) {
+                                next_deferred_redraw =
+                                    Some(next_deferred_redraw.map_or(due, |next| next.min(due)));
+                            }
                         }
                     })
                 }
 
                 if event_loop_target.control_flow() == ControlFlow::Wait {
-                    if let Some(next_timer) = corelib::platform::duration_until_next_timer_update()
-                    {
-                        event_loop_target.set_control_flow(ControlFlow::wait_duration(next_timer));
+                    let next_timer = corelib::platform::duration_until_next_timer_update();
+                    let wait = match (next_timer, next_deferred_redraw) {
+                        (Some(a), Some(b)) => Some(a.min(b)),
+                        (a, b) => a.or(b),
+                    };
+                    if let Some(wait) = wait {
+                        event_loop_target.set_control_flow(ControlFlow::wait_duration(wait));
                     }
                 }
             }
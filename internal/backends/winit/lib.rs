@@ -7,6 +7,7 @@
 extern crate alloc;
 
 use event_loop::CustomEvent;
+use i_slint_core::api::PhysicalSize;
 use i_slint_core::platform::EventLoopProxy;
 use i_slint_core::window::WindowAdapter;
 use renderer::WinitCompatibleRenderer;
@@ -15,11 +16,22 @@
 mod winitwindowadapter;
 use i_slint_core::platform::PlatformError;
 use winitwindowadapter::*;
+mod accelerator;
 pub(crate) mod event_loop;
+pub use accelerator::Accelerator;
+mod monitor;
+pub use monitor::MonitorInfo;
+mod touch;
+pub use touch::{TouchEvent, TouchPhase};
+mod posted_events;
+pub use posted_events::{post_event, post_event_after, PostedEventHandle};
 
 /// Re-export of the winit crate.
 pub use winit;
 
+/// Re-export of the raw-window-handle crate, in the version used by winit.
+pub use raw_window_handle;
+
 /// Internal type used by the winit backend for thread communication and window system updates.
 #[non_exhaustive]
 #[derive(Debug)]
@@ -27,6 +39,161 @@ pub enum SlintUserEvent {
     CustomEvent { event: CustomEvent },
 }
 
+/// The fullscreen state of a window, used with [`WinitWindowAccessor::set_fullscreen_mode`].
+#[derive(Clone, Debug, PartialEq, Default)]
+pub enum FullscreenMode {
+    /// The window is shown as a regular window.
+    #[default]
+    Windowed,
+    /// The window covers the entire monitor it's currently on, without changing the video mode.
+    Borderless,
+    /// The window covers the entire monitor and the monitor switches to the specified video mode,
+    /// which must be one of the [`MonitorInfo::video_modes`] of a connected monitor. The desktop
+    /// video mode is restored when leaving exclusive fullscreen. This isn't supported on wasm32.
+    Exclusive(winit::monitor::VideoMode),
+}
+
+/// The kind of graphics API used to render a window, as part of [`GraphicsApiInfo`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphicsApiKind {
+    /// Desktop OpenGL.
+    OpenGL,
+    /// OpenGL ES, as used on mobile and embedded devices.
+    OpenGLES,
+    /// WebGL in the browser.
+    WebGL,
+    /// The window is rendered on the CPU.
+    Software,
+}
+
+/// The OpenGL API and version to request when creating OpenGL contexts, used with
+/// [`Backend::set_requested_opengl_api`]. The version is given as `(major, minor)`; `None`
+/// leaves the choice of the version to the driver.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RequestedOpenGLApi {
+    /// Desktop OpenGL. Versions 3.2 and later are requested with the core profile.
+    OpenGL(Option<(u8, u8)>),
+    /// OpenGL ES, as used on mobile and embedded devices.
+    OpenGLES(Option<(u8, u8)>),
+}
+
+/// How presenting a frame is synchronized with the refresh of the display, used with
+/// [`Backend::set_swap_interval`] and reported in [`GraphicsApiInfo::swap_interval`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SwapInterval {
+    /// Frames are presented as soon as they're rendered, which may cause tearing.
+    Immediate,
+    /// Frames are presented at the next vertical blank of the display, which avoids tearing.
+    #[default]
+    VSync,
+}
+
+/// Describes the graphics API used to render a window, as returned by [`WinitWindowAccessor::graphics_api_info`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphicsApiInfo {
+    /// The kind of graphics API.
+    pub kind: GraphicsApiKind,
+    /// The version string of the implementation, such as `GL_VERSION` for OpenGL.
+    pub version: String,
+    /// The name of the renderer, such as `GL_RENDERER` for OpenGL.
+    pub renderer: String,
+    /// The swap interval that the driver accepted for the window, or `None` if the renderer
+    /// doesn't control it or the driver rejected all swap intervals.
+    pub swap_interval: Option<SwapInterval>,
+}
+
+/// An event about files being dragged onto a window, passed to the callback set with
+/// [`WinitWindowAccessor::on_file_drop_event`].
+///
+/// Every [`FileDropEvent::Hovered`] is followed by either [`FileDropEvent::Dropped`] or
+/// [`FileDropEvent::HoverCancelled`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileDropEvent {
+    /// Files are being dragged over the window.
+    Hovered {
+        /// The paths of all files that are part of the drag gesture.
+        paths: Vec<std::path::PathBuf>,
+        /// The last known position of the mouse cursor in the window.
+        position: i_slint_core::api::LogicalPosition,
+    },
+    /// The files were dragged out of the window, or the drag was cancelled.
+    HoverCancelled,
+    /// Files were dropped onto the window.
+    Dropped {
+        /// The paths of all files dropped with a single gesture.
+        paths: Vec<std::path::PathBuf>,
+        /// The last known position of the mouse cursor in the window.
+        position: i_slint_core::api::LogicalPosition,
+    },
+}
+
+/// A scroll event from a mouse wheel or a touchpad, passed to the callback set with
+/// [`WinitWindowAccessor::on_scroll_event`].
+///
+/// The direction of the deltas is the one reported by the operating system, including any natural
+/// scrolling setting of the user, and matches the deltas delivered to the Slint elements.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScrollEvent {
+    /// The horizontal scroll distance, in logical pixels.
+    pub delta_x: f32,
+    /// The vertical scroll distance, in logical pixels.
+    pub delta_y: f32,
+    /// True if the deltas come from a device with high precision, such as a touchpad, that
+    /// reports distances in pixels. False for mouse wheels that scroll in steps of lines, in
+    /// which case every line is converted to 60 logical pixels.
+    pub precise: bool,
+    /// The last known position of the mouse cursor in the window.
+    pub position: i_slint_core::api::LogicalPosition,
+}
+
+/// A key press or release, passed to the callback set with [`WinitWindowAccessor::on_keyboard_event`].
+///
+/// Use [`Self::physical_key`] for shortcuts that should stay at the same position on the keyboard
+/// regardless of the keyboard layout, and [`Self::logical_key`] or [`Self::text`] for shortcuts
+/// that follow the layout.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyboardEvent {
+    /// The key's position on the keyboard, which doesn't change when the user switches keyboard layouts.
+    /// For example, the key labeled "Z" on a QWERTY keyboard is reported as `KeyCode::KeyZ`, also
+    /// when a German layout places "Y" on it.
+    pub physical_key: winit::keyboard::PhysicalKey,
+    /// The key as interpreted by the current keyboard layout, without the effect of modifiers on
+    /// the character: Shift+1 is reported as "1" on a US layout, not as "!". On wasm32, Android
+    /// and iOS, where winit can't determine the key without modifiers, it includes the effect of
+    /// all modifiers except Control. Dead keys are reported as `Key::Dead`.
+    pub logical_key: winit::keyboard::Key,
+    /// The text the key produces, if any. This is `None` for dead keys, which only produce text
+    /// in combination with the next key. Text composed with an input method isn't delivered as
+    /// keyboard events.
+    pub text: Option<i_slint_core::SharedString>,
+    /// True if the key was pressed, false if it was released.
+    pub pressed: bool,
+    /// True if this is a repeated press because the key is held down.
+    pub repeat: bool,
+    /// The modifier keys that were held down when the key was pressed or released.
+    pub modifiers: winit::keyboard::ModifiersState,
+    /// True if an input method is composing text, in which case the key usually belongs to the composition.
+    pub composing: bool,
+}
+
+/// The direction in which the keyboard focus is about to move, passed to the callback set with
+/// [`WinitWindowAccessor::on_focus_traversal`].
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FocusTraversal {
+    /// Tab was pressed: the focus moves to the next element in the focus chain.
+    Next,
+    /// Shift+Tab was pressed: the focus moves to the previous element in the focus chain.
+    Previous,
+}
+
 mod renderer {
     use i_slint_core::platform::PlatformError;
 
@@ -42,6 +209,10 @@ fn occluded(&self, _: bool) {}
         fn resumed(&self, _winit_window: &winit::window::Window) -> Result<(), PlatformError> {
             Ok(())
         }
+
+        fn graphics_api_info(&self) -> Option<crate::GraphicsApiInfo> {
+            None
+        }
     }
 
     #[cfg(feature = "renderer-femtovg")]
@@ -69,54 +240,63 @@ pub fn create_gl_window_with_canvas_id(
     Ok(adapter)
 }
 
+type RendererFactoryFn =
+    fn(
+        window_builder: winit::window::WindowBuilder,
+    ) -> Result<(Box<dyn WinitCompatibleRenderer>, Rc<winit::window::Window>), PlatformError>;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "renderer-femtovg")] {
         const DEFAULT_RENDERER_NAME: &str = "FemtoVG";
+        const DEFAULT_RENDERER_FACTORY: RendererFactoryFn = renderer::femtovg::GlutinFemtoVGRenderer::new;
     } else if #[cfg(enable_skia_renderer)] {
         const DEFAULT_RENDERER_NAME: &'static str = "Skia";
+        const DEFAULT_RENDERER_FACTORY: RendererFactoryFn = renderer::skia::WinitSkiaRenderer::new;
     } else if #[cfg(feature = "renderer-software")] {
         const DEFAULT_RENDERER_NAME: &'static str = "Software";
+        const DEFAULT_RENDERER_FACTORY: RendererFactoryFn = renderer::sw::WinitSoftwareRenderer::new;
     } else {
         compile_error!("Please select a feature to build with the winit backend: `renderer-femtovg`, `renderer-skia`, `renderer-skia-opengl`, `renderer-skia-vulkan` or `renderer-software`");
     }
 }
 
-fn default_renderer_factory(
-    window_builder: winit::window::WindowBuilder,
-) -> Result<(Box<dyn WinitCompatibleRenderer>, Rc<winit::window::Window>), PlatformError> {
-    cfg_if::cfg_if! {
-        if #[cfg(feature = "renderer-femtovg")] {
-            renderer::femtovg::GlutinFemtoVGRenderer::new(window_builder)
-        } else if #[cfg(enable_skia_renderer)] {
-            renderer::skia::WinitSkiaRenderer::new(window_builder)
-        } else if #[cfg(feature = "renderer-software")] {
-            renderer::sw::WinitSoftwareRenderer::new(window_builder)
-        } else {
-            compile_error!("Please select a feature to build with the winit backend: `renderer-femtovg`, `renderer-skia`, `renderer-skia-opengl`, `renderer-skia-vulkan` or `renderer-software`");
-        }
-    }
-}
-
+/// Tries the other compiled-in renderers after `failed_renderer_factory` failed with
+/// `failed_renderer_error`. If none of them works, the returned error lists all failures.
 fn try_create_window_with_fallback_renderer(
     builder: winit::window::WindowBuilder,
-) -> Option<Rc<WinitWindowAdapter>> {
-    [
+    failed_renderer_factory: RendererFactoryFn,
+    failed_renderer_error: PlatformError,
+) -> Result<Rc<WinitWindowAdapter>, PlatformError> {
+    let mut errors = vec![failed_renderer_error.to_string()];
+    for (renderer_name, renderer_factory) in [
         #[cfg(any(
             feature = "renderer-skia",
             feature = "renderer-skia-opengl",
             feature = "renderer-skia-vulkan"
         ))]
-        renderer::skia::WinitSkiaRenderer::new,
+        ("Skia", renderer::skia::WinitSkiaRenderer::new as RendererFactoryFn),
         #[cfg(any(feature = "renderer-femtovg"))]
-        renderer::femtovg::GlutinFemtoVGRenderer::new,
+        ("FemtoVG", renderer::femtovg::GlutinFemtoVGRenderer::new as RendererFactoryFn),
         #[cfg(any(feature = "renderer-software"))]
-        renderer::sw::WinitSoftwareRenderer::new,
-    ]
-    .into_iter()
-    .find_map(|renderer_factory| {
-        let (renderer, winit_window) = renderer_factory(builder.clone()).ok()?;
-        Some(WinitWindowAdapter::new(renderer, winit_window))
-    })
+        ("Software", renderer::sw::WinitSoftwareRenderer::new as RendererFactoryFn),
+    ] {
+        if renderer_factory as usize == failed_renderer_factory as usize {
+            continue;
+        }
+        match renderer_factory(builder.clone()) {
+            Ok((renderer, winit_window)) => {
+                i_slint_core::debug_log!(
+                    "slint winit: falling back to the {renderer_name} renderer"
+                );
+                return Ok(WinitWindowAdapter::new(renderer, winit_window));
+            }
+            Err(e) => {
+                i_slint_core::debug_log!("slint winit: the {renderer_name} renderer failed: {e}");
+                errors.push(format!("{renderer_name} renderer: {e}"));
+            }
+        }
+    }
+    Err(format!("Winit backend failed to find a suitable renderer: {}", errors.join("; ")).into())
 }
 
 #[doc(hidden)]
@@ -136,11 +316,7 @@ pub mod native_widgets {}
 /// slint::platform::set_platform(Box::new(Backend::new().unwrap()));
 /// ```
 pub struct Backend {
-    renderer_factory_fn:
-        fn(
-            window_builder: winit::window::WindowBuilder,
-        )
-            -> Result<(Box<dyn WinitCompatibleRenderer>, Rc<winit::window::Window>), PlatformError>,
+    renderer_factory_fn: RendererFactoryFn,
     event_loop_state: std::cell::RefCell<Option<crate::event_loop::EventLoopState>>,
 
     /// This hook is called before a Window is created.
@@ -181,13 +357,12 @@ pub fn new_with_renderer_by_name(renderer_name: Option<&str>) -> Result<Self, Pl
             Some("skia-software") => renderer::skia::WinitSkiaRenderer::new_software,
             #[cfg(feature = "renderer-software")]
             Some("sw") | Some("software") => renderer::sw::WinitSoftwareRenderer::new,
-            None => default_renderer_factory,
+            None => DEFAULT_RENDERER_FACTORY,
             Some(renderer_name) => {
-                eprintln!(
-                    "slint winit: unrecognized renderer {}, falling back to {}",
-                    renderer_name, DEFAULT_RENDERER_NAME
+                i_slint_core::debug_log!(
+                    "slint winit: unrecognized renderer {renderer_name}, falling back to {DEFAULT_RENDERER_NAME}"
                 );
-                default_renderer_factory
+                DEFAULT_RENDERER_FACTORY
             }
         };
         Ok(Self {
@@ -196,6 +371,211 @@ pub fn new_with_renderer_by_name(renderer_name: Option<&str>) -> Result<Self, Pl
             window_builder_hook: None,
         })
     }
+
+    /// Returns the names of the renderers compiled into this build, which can be passed to
+    /// [`Self::new_with_renderer_by_name()`]. The first entry is the renderer selected by default,
+    /// followed by the others in order of priority.
+    ///
+    /// Whether a renderer can actually be used also depends on the system, for example on the availability
+    /// of OpenGL drivers.
+    pub fn available_renderers() -> &'static [&'static str] {
+        &[
+            #[cfg(feature = "renderer-femtovg")]
+            "femtovg",
+            #[cfg(enable_skia_renderer)]
+            "skia",
+            #[cfg(all(enable_skia_renderer, not(target_os = "android")))]
+            "skia-software",
+            #[cfg(feature = "renderer-software")]
+            "software",
+        ]
+    }
+
+    /// Returns the name of the windowing system the backend is connected to, such as `"x11"`,
+    /// `"wayland"`, `"windows"`, `"macos"`, or `"web"`. This is useful for diagnostics, for example
+    /// when logging which platform an application ended up on.
+    pub fn window_system_name(&self) -> &'static str {
+        cfg_if::cfg_if! {
+            if #[cfg(target_arch = "wasm32")] {
+                "web"
+            } else if #[cfg(target_os = "windows")] {
+                "windows"
+            } else if #[cfg(target_os = "macos")] {
+                "macos"
+            } else if #[cfg(target_os = "ios")] {
+                "ios"
+            } else if #[cfg(target_os = "android")] {
+                "android"
+            } else {
+                crate::event_loop::with_window_target(|_event_loop| {
+                    #[cfg(feature = "wayland")]
+                    {
+                        use winit::platform::wayland::EventLoopWindowTargetExtWayland;
+                        if _event_loop.event_loop_target().is_wayland() {
+                            return Ok("wayland");
+                        }
+                    }
+                    #[cfg(feature = "x11")]
+                    {
+                        use winit::platform::x11::EventLoopWindowTargetExtX11;
+                        if _event_loop.event_loop_target().is_x11() {
+                            return Ok("x11");
+                        }
+                    }
+                    Ok("unknown")
+                })
+                .unwrap_or("unknown")
+            }
+        }
+    }
+
+    /// Returns information about all monitors connected to the system.
+    ///
+    /// This can be used to size and position windows before they're shown. The list may be empty
+    /// on platforms that don't provide this information, such as the web.
+    pub fn available_monitors(&self) -> Vec<MonitorInfo> {
+        crate::event_loop::with_window_target(|event_loop| {
+            let event_loop_target = event_loop.event_loop_target();
+            let primary_monitor = event_loop_target.primary_monitor();
+            Ok(event_loop_target
+                .available_monitors()
+                .map(|monitor| MonitorInfo::from_winit(&monitor, primary_monitor.as_ref()))
+                .collect())
+        })
+        .unwrap_or_default()
+    }
+
+    /// Limits how often windows are redrawn, by setting the minimum time between two frames of the same window.
+    /// For example, pass `Some(Duration::from_secs(1) / 30)` to render at most 30 frames per second,
+    /// which can save battery on displays with a high refresh rate or when vsync is off.
+    ///
+    /// Redraws requested sooner are not dropped, but coalesced into a single redraw at the next permitted
+    /// time. Animations keep their timing, as they're driven by the elapsed time, and are shown
+    /// with fewer intermediate frames. Pass `None` to remove the limit, which is the default.
+    pub fn set_frame_interval(&self, interval: Option<core::time::Duration>) {
+        crate::event_loop::set_min_frame_interval(interval)
+    }
+
+    /// Selects the OpenGL API and version that the FemtoVG renderer requests for windows created
+    /// afterwards. For example, pass `Some(RequestedOpenGLApi::OpenGLES(Some((2, 0))))` to test
+    /// with the same API as on embedded devices.
+    ///
+    /// By default, OpenGL ES 2.0 is tried first, falling back to the driver's default OpenGL
+    /// context. When an API is requested, there's no fallback: creating the window fails with an
+    /// error if the driver doesn't support the requested API and version. Pass `None` to restore
+    /// the default. This has no effect on the other renderers and on wasm32.
+    pub fn set_requested_opengl_api(&self, api: Option<RequestedOpenGLApi>) {
+        crate::event_loop::set_requested_opengl_api(api)
+    }
+
+    /// Selects the swap interval that the FemtoVG renderer requests for windows created afterwards.
+    /// The default is [`SwapInterval::VSync`].
+    ///
+    /// If the driver rejects [`SwapInterval::VSync`], [`SwapInterval::Immediate`] is tried next. Use
+    /// [`WinitWindowAccessor::graphics_api_info()`] to find out which swap interval a window ended up
+    /// with. This has no effect on the other renderers and on wasm32, where the browser decides.
+    pub fn set_swap_interval(&self, swap_interval: SwapInterval) {
+        crate::event_loop::set_swap_interval(swap_interval)
+    }
+
+    /// Processes the pending events of the event loop and returns, for applications that have their
+    /// own main loop, such as a game engine, and can't call `slint::run_event_loop()`, which only
+    /// returns when the event loop is quit.
+    ///
+    /// If there are no pending events, this waits for up to `timeout` for new events to arrive. A timeout
+    /// of zero only processes the pending events, `None` waits until there's at least one event. Timers
+    /// and animations advance with every call, and windows are redrawn as needed, so call this regularly,
+    /// for example once per iteration of the application's main loop.
+    ///
+    /// Returns [`ControlFlow::Break`](core::ops::ControlFlow::Break) when the event loop was quit, for
+    /// example with `slint::quit_event_loop()` or because the last window was closed. A subsequent call
+    /// starts the event loop again.
+    ///
+    /// This isn't available on wasm32, where the browser drives the event loop.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pump_events(
+        &self,
+        timeout: Option<core::time::Duration>,
+    ) -> Result<core::ops::ControlFlow<()>, PlatformError> {
+        let loop_state = self.event_loop_state.borrow_mut().take().unwrap_or_default();
+        let (new_state, status) = loop_state.pump_events(timeout)?;
+        *self.event_loop_state.borrow_mut() = Some(new_state);
+        match status {
+            winit::platform::pump_events::PumpStatus::Continue => {
+                Ok(core::ops::ControlFlow::Continue(()))
+            }
+            winit::platform::pump_events::PumpStatus::Exit(code) => {
+                if code == 0 {
+                    Ok(core::ops::ControlFlow::Break(()))
+                } else {
+                    Err(format!("Event loop exited with non-zero code {code}").into())
+                }
+            }
+        }
+    }
+
+    /// Changes whether the event loop quits when the last window is closed. This overrides the behavior
+    /// selected when starting the event loop with `run_event_loop()` or `run_event_loop_until_quit()`,
+    /// and can be called while the event loop is running, for example to keep the application
+    /// running in the system tray depending on its state.
+    ///
+    /// When passing `true` while no window is visible, the event loop quits.
+    pub fn set_quit_on_last_window_closed(&self, quit_on_last_window_closed: bool) {
+        i_slint_core::platform::set_quit_on_last_window_closed(quit_on_last_window_closed)
+    }
+
+    /// Returns true if the default clipboard contains text.
+    ///
+    /// The clipboard libraries used by this backend don't provide a way of checking for text
+    /// without retrieving it, so this fetches the clipboard contents and checks that they're not empty.
+    pub fn clipboard_has_text(&self) -> bool {
+        i_slint_core::platform::Platform::clipboard_text(
+            self,
+            i_slint_core::platform::Clipboard::DefaultClipboard,
+        )
+        .map_or(false, |text| !text.is_empty())
+    }
+
+    /// Removes the contents of the default clipboard, by replacing them with an empty text.
+    pub fn clear_clipboard(&self) {
+        i_slint_core::platform::Platform::set_clipboard_text(
+            self,
+            "",
+            i_slint_core::platform::Clipboard::DefaultClipboard,
+        );
+    }
+
+    /// Sets the contents of the primary selection to the specified text. The primary selection is used
+    /// on X11 and Wayland for pasting with the middle mouse button.
+    ///
+    /// On platforms without a primary selection, such as Windows, macOS, or the web, this function does nothing.
+    pub fn set_primary_selection_text(&self, text: &str) {
+        #[cfg(enable_primary_selection)]
+        i_slint_core::platform::Platform::set_clipboard_text(
+            self,
+            text,
+            i_slint_core::platform::Clipboard::SelectionClipboard,
+        );
+        #[cfg(not(enable_primary_selection))]
+        let _ = text;
+    }
+
+    /// Returns the text currently stored in the primary selection, if any.
+    ///
+    /// On platforms without a primary selection, such as Windows, macOS, or the web, this function
+    /// always returns `None`.
+    pub fn primary_selection_text(&self) -> Option<String> {
+        cfg_if::cfg_if! {
+            if #[cfg(enable_primary_selection)] {
+                i_slint_core::platform::Platform::clipboard_text(
+                    self,
+                    i_slint_core::platform::Clipboard::SelectionClipboard,
+                )
+            } else {
+                None
+            }
+        }
+    }
 }
 
 fn send_event_via_global_event_loop_proxy(
@@ -245,8 +625,7 @@ fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError>
         let adapter = (self.renderer_factory_fn)(builder.clone())
             .map(|(renderer, window)| WinitWindowAdapter::new(renderer, window))
             .or_else(|e| {
-                try_create_window_with_fallback_renderer(builder)
-                    .ok_or_else(|| format!("Winit backend failed to find a suitable renderer: {e}"))
+                try_create_window_with_fallback_renderer(builder, self.renderer_factory_fn, e)
             })?;
         Ok(adapter)
     }
@@ -264,21 +643,7 @@ fn process_events(
         timeout: core::time::Duration,
         _: i_slint_core::InternalToken,
     ) -> Result<core::ops::ControlFlow<()>, PlatformError> {
-        let loop_state = self.event_loop_state.borrow_mut().take().unwrap_or_default();
-        let (new_state, status) = loop_state.pump_events(Some(timeout))?;
-        *self.event_loop_state.borrow_mut() = Some(new_state);
-        match status {
-            winit::platform::pump_events::PumpStatus::Continue => {
-                Ok(core::ops::ControlFlow::Continue(()))
-            }
-            winit::platform::pump_events::PumpStatus::Exit(code) => {
-                if code == 0 {
-                    Ok(core::ops::ControlFlow::Break(()))
-                } else {
-                    return Err(format!("Event loop exited with non-zero code {code}").into());
-                }
-            }
-        }
+        self.pump_events(Some(timeout))
     }
 
     fn new_event_loop_proxy(&self) -> Option<Box<dyn EventLoopProxy>> {
@@ -367,6 +732,137 @@ pub trait WinitWindowAccessor: private::WinitWindowAccessorSealed {
     /// and returns `Some(T)`; otherwise `None`.
     fn with_winit_window<T>(&self, callback: impl FnOnce(&winit::window::Window) -> T)
         -> Option<T>;
+    /// Returns the handle of the native window, for example to create a Vulkan or wgpu surface for it,
+    /// or `None` if the window isn't backed by this winit backend.
+    ///
+    /// The handle remains valid only as long as the Slint window exists. Don't use it after the
+    /// window was dropped.
+    fn raw_window_handle(&self) -> Option<raw_window_handle::RawWindowHandle>;
+    /// Returns the handle of the display connection the window belongs to, or `None` if the window
+    /// isn't backed by this winit backend. The same lifetime restrictions as for [`Self::raw_window_handle()`] apply.
+    fn raw_display_handle(&self) -> Option<raw_window_handle::RawDisplayHandle>;
+    /// Sets the icon shown for example in the task bar or the title bar of the window, from the
+    /// specified RGBA pixel buffer. This takes precedence over the `icon` property of the `Window` element.
+    ///
+    /// Returns an error if the buffer is empty or if the window isn't backed by this winit backend.
+    /// On wasm32, this function does nothing.
+    fn set_window_icon(
+        &self,
+        icon: i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>,
+    ) -> Result<(), PlatformError>;
+    /// Sets the title of the window, which takes precedence over the `title` property of the `Window`
+    /// element. Pass `None` to show the `title` property again. On wasm32, the title is also used
+    /// as the title of the HTML document.
+    ///
+    /// Returns an error if the window isn't backed by this winit backend.
+    fn set_window_title(&self, title: Option<&str>) -> Result<(), PlatformError>;
+    /// Returns the title of the window, as set with [`Self::set_window_title()`] or the `title`
+    /// property of the `Window` element, or `None` if the window isn't backed by this winit backend.
+    fn window_title(&self) -> Option<String>;
+    /// Changes the fullscreen mode of the window. When switching back to [`FullscreenMode::Windowed`],
+    /// the size and position the window had before entering fullscreen are restored, once the
+    /// window system reports that the window left fullscreen.
+    ///
+    /// Returns an error if the window isn't backed by this winit backend, or if the mode isn't
+    /// supported on this platform.
+    fn set_fullscreen_mode(&self, mode: FullscreenMode) -> Result<(), PlatformError>;
+    /// Returns the current fullscreen mode of the window, or `None` if the window isn't backed by this winit backend.
+    fn fullscreen_mode(&self) -> Option<FullscreenMode>;
+    /// Sets the minimum size the user can resize the window to, in addition to the minimum size
+    /// imposed by the layout. Pass `None` to remove the limit. While the window is in fullscreen,
+    /// the limit takes effect when switching back to [`FullscreenMode::Windowed`].
+    fn set_min_inner_size(&self, size: Option<i_slint_core::api::WindowSize>);
+    /// Sets the maximum size the user can resize the window to, in addition to the maximum size
+    /// imposed by the layout. Pass `None` to remove the limit. While the window is in fullscreen,
+    /// the limit takes effect when switching back to [`FullscreenMode::Windowed`].
+    fn set_max_inner_size(&self, size: Option<i_slint_core::api::WindowSize>);
+    /// Sets the mouse cursor shown over the window, overriding the `mouse-cursor` property of the
+    /// elements under the mouse. Pass `None` to go back to the cursor requested by the elements.
+    fn set_cursor_icon(&self, icon: Option<winit::window::CursorIcon>);
+    /// Shows or hides the mouse cursor while it's over the window.
+    fn set_cursor_visible(&self, visible: bool);
+    /// Sets the stacking level of the window relative to other windows, overriding the `always-on-top`
+    /// property of the `Window` element. Pass `None` to go back to the level implied by that property.
+    ///
+    /// To set the level before the window is shown, use [`Backend::window_builder_hook`] with
+    /// [`winit::window::WindowBuilder::with_window_level`].
+    fn set_window_level(&self, level: Option<winit::window::WindowLevel>);
+    /// Grabs the mouse cursor, for example to keep receiving mouse movements during a drag operation
+    /// when the cursor would leave the window. Pass [`winit::window::CursorGrabMode::None`] to release it.
+    ///
+    /// [`CursorGrabMode::Confined`](winit::window::CursorGrabMode::Confined) keeps the cursor inside the
+    /// window and is supported on Windows, X11 and Wayland. [`CursorGrabMode::Locked`](winit::window::CursorGrabMode::Locked)
+    /// keeps the cursor in place and is supported on macOS, Wayland and the web. An error is returned if the
+    /// mode isn't supported, so that the application can fall back to the other one.
+    fn set_cursor_grab(&self, mode: winit::window::CursorGrabMode) -> Result<(), PlatformError>;
+    /// Minimizes the window to the task bar or dock, or restores it when passing `false`.
+    ///
+    /// Windows are created hidden and only appear once shown with `Window::show()`, so this
+    /// can be called before showing the window. On Wayland, windows can't be restored programmatically.
+    fn set_minimized(&self, minimized: bool);
+    /// Returns true if the window is minimized. Returns `None` if the state can't be determined,
+    /// which is the case on Wayland, or if the window isn't backed by this winit backend.
+    fn is_minimized(&self) -> Option<bool>;
+    /// Maximizes the window, or restores it to the size and position it had before being maximized
+    /// when passing `false`.
+    fn set_maximized(&self, maximized: bool);
+    /// Returns true if the window is maximized; false otherwise or if the window isn't backed by this winit backend.
+    fn is_maximized(&self) -> bool;
+    /// Returns the position of the mouse cursor relative to the top-left corner of the window, in logical pixels.
+    ///
+    /// Returns `None` if the cursor isn't over the window, if it hasn't moved since the window was shown,
+    /// or if the window isn't backed by this winit backend.
+    fn cursor_position(&self) -> Option<i_slint_core::api::LogicalPosition>;
+    /// Moves the mouse cursor to the specified position relative to the top-left corner of the window, in logical pixels.
+    ///
+    /// Returns an error if the window isn't backed by this winit backend, or if moving the cursor
+    /// isn't supported on this platform, such as on the web, iOS, Android, or Wayland.
+    fn set_cursor_position(
+        &self,
+        position: i_slint_core::api::LogicalPosition,
+    ) -> Result<(), PlatformError>;
+    /// Returns information about the monitor the window is currently on, or `None` if it can't be
+    /// determined or if the window isn't backed by this winit backend.
+    fn current_monitor(&self) -> Option<MonitorInfo>;
+    /// Sets a callback that's invoked when the window was moved to a different monitor, with the
+    /// information about the new monitor. Use this for example to adapt to the new monitor's
+    /// [`MonitorInfo::refresh_rate_millihertz`].
+    fn on_monitor_changed(&self, callback: impl FnMut(&MonitorInfo) + 'static);
+    /// Sets a callback that's invoked when the scale factor of the window changes, for example when
+    /// it's moved to a monitor with a different DPI. The callback receives the old scale factor, the new
+    /// scale factor, and the new size of the window in physical pixels.
+    ///
+    /// The callback is not invoked if the scale factor is overridden with the `SLINT_SCALE_FACTOR` environment variable.
+    fn on_scale_factor_changed(&self, callback: impl FnMut(f32, f32, PhysicalSize) + 'static);
+    /// Sets a callback that's invoked when files are dragged over or dropped onto the window.
+    /// Files dropped with a single gesture are delivered together in one [`FileDropEvent::Dropped`].
+    fn on_file_drop_event(&self, callback: impl FnMut(&FileDropEvent) + 'static);
+    /// Sets a callback that's invoked for every touch on the window, as well as for pinch and pan
+    /// gestures performed with two fingers. Independent of this callback, the first finger touching
+    /// the window is also delivered to the Slint elements as mouse input.
+    fn on_touch_event(&self, callback: impl FnMut(&TouchEvent) + 'static);
+    /// Sets a callback that's invoked for every scroll event, before it's delivered to the Slint elements.
+    /// Unlike the elements, the callback can tell apart precise scrolling on a touchpad from the steps of
+    /// a mouse wheel. On macOS, pinching on the touchpad is reported as [`TouchEvent::Pinch`] to the
+    /// callback set with [`Self::on_touch_event()`].
+    fn on_scroll_event(&self, callback: impl FnMut(&ScrollEvent) + 'static);
+    /// Sets a callback that's invoked for every key press and release, before it's delivered to the
+    /// Slint elements. Unlike the elements, the callback receives the physical key along with the
+    /// layout dependent key and text.
+    fn on_keyboard_event(&self, callback: impl FnMut(&KeyboardEvent) + 'static);
+    /// Sets a callback that's invoked with `true` when the window gains the keyboard focus of the
+    /// windowing system, and with `false` when it loses it. When the window loses the focus, the
+    /// Slint elements also lose their hover and pressed state.
+    fn on_focus_changed(&self, callback: impl FnMut(&bool) + 'static);
+    /// Sets a callback that's invoked when Tab or Shift+Tab is pressed, before Slint moves the keyboard
+    /// focus to the next or previous element. Return `true` from the callback to prevent Slint from
+    /// moving the focus and from delivering the key press to the Slint elements.
+    fn on_focus_traversal(&self, callback: impl FnMut(&FocusTraversal) -> bool + 'static);
+    /// Returns information about the graphics API used to render the window, or `None` if the
+    /// renderer doesn't provide it or the window isn't backed by this winit backend.
+    ///
+    /// This can be called before the window is shown.
+    fn graphics_api_info(&self) -> Option<GraphicsApiInfo>;
 }
 
 impl WinitWindowAccessor for i_slint_core::api::Window {
@@ -380,10 +876,181 @@ fn with_winit_window<T>(
     ) -> Option<T> {
         winit_window_rc_for_window(self).as_ref().map(|w| callback(w))
     }
+
+    fn raw_window_handle(&self) -> Option<raw_window_handle::RawWindowHandle> {
+        use raw_window_handle::HasRawWindowHandle;
+        winit_window_rc_for_window(self).map(|w| w.raw_window_handle())
+    }
+
+    fn raw_display_handle(&self) -> Option<raw_window_handle::RawDisplayHandle> {
+        use raw_window_handle::HasRawDisplayHandle;
+        winit_window_rc_for_window(self).map(|w| w.raw_display_handle())
+    }
+
+    fn set_window_icon(
+        &self,
+        icon: i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>,
+    ) -> Result<(), PlatformError> {
+        with_winit_window_adapter(self, |adapter| adapter.set_window_icon(icon))
+            .unwrap_or_else(|| Err(not_a_winit_window_error()))
+    }
+
+    fn set_window_title(&self, title: Option<&str>) -> Result<(), PlatformError> {
+        with_winit_window_adapter(self, |adapter| adapter.set_title(title))
+            .ok_or_else(not_a_winit_window_error)
+    }
+
+    fn window_title(&self) -> Option<String> {
+        with_winit_window_adapter(self, |adapter| adapter.title())
+    }
+
+    fn set_fullscreen_mode(&self, mode: FullscreenMode) -> Result<(), PlatformError> {
+        with_winit_window_adapter(self, |adapter| adapter.set_fullscreen_mode(mode))
+            .unwrap_or_else(|| Err(not_a_winit_window_error()))
+    }
+
+    fn fullscreen_mode(&self) -> Option<FullscreenMode> {
+        with_winit_window_adapter(self, |adapter| adapter.fullscreen_mode())
+    }
+
+    fn set_min_inner_size(&self, size: Option<i_slint_core::api::WindowSize>) {
+        with_winit_window_adapter(self, |adapter| adapter.set_min_inner_size(size));
+    }
+
+    fn set_max_inner_size(&self, size: Option<i_slint_core::api::WindowSize>) {
+        with_winit_window_adapter(self, |adapter| adapter.set_max_inner_size(size));
+    }
+
+    fn set_cursor_icon(&self, icon: Option<winit::window::CursorIcon>) {
+        with_winit_window_adapter(self, |adapter| adapter.set_cursor_icon(icon));
+    }
+
+    fn set_cursor_visible(&self, visible: bool) {
+        with_winit_window_adapter(self, |adapter| adapter.set_cursor_visible(visible));
+    }
+
+    fn set_window_level(&self, level: Option<winit::window::WindowLevel>) {
+        with_winit_window_adapter(self, |adapter| adapter.set_window_level(level));
+    }
+
+    fn set_cursor_grab(&self, mode: winit::window::CursorGrabMode) -> Result<(), PlatformError> {
+        let winit_window = winit_window_rc_for_window(self).ok_or_else(not_a_winit_window_error)?;
+        winit_window.set_cursor_grab(mode).map_err(|e| match e {
+            winit::error::ExternalError::NotSupported(_) => {
+                format!("Cursor grab mode {mode:?} is not supported on this platform").into()
+            }
+            e => format!("Error setting cursor grab mode {mode:?}: {e}").into(),
+        })
+    }
+
+    fn set_minimized(&self, minimized: bool) {
+        if let Some(winit_window) = winit_window_rc_for_window(self) {
+            winit_window.set_minimized(minimized);
+        }
+    }
+
+    fn is_minimized(&self) -> Option<bool> {
+        winit_window_rc_for_window(self)?.is_minimized()
+    }
+
+    fn set_maximized(&self, maximized: bool) {
+        if let Some(winit_window) = winit_window_rc_for_window(self) {
+            winit_window.set_maximized(maximized);
+        }
+    }
+
+    fn is_maximized(&self) -> bool {
+        winit_window_rc_for_window(self).map_or(false, |winit_window| winit_window.is_maximized())
+    }
+
+    fn cursor_position(&self) -> Option<i_slint_core::api::LogicalPosition> {
+        with_winit_window_adapter(self, |adapter| adapter.cursor_position.get()).flatten()
+    }
+
+    fn set_cursor_position(
+        &self,
+        position: i_slint_core::api::LogicalPosition,
+    ) -> Result<(), PlatformError> {
+        let winit_window = winit_window_rc_for_window(self).ok_or_else(not_a_winit_window_error)?;
+        // Use Slint's scale factor, which may be overridden with SLINT_SCALE_FACTOR, to match the
+        // positions reported in the mouse events.
+        let position = position.to_physical(self.scale_factor());
+        winit_window
+            .set_cursor_position(winit::dpi::PhysicalPosition::new(position.x, position.y))
+            .map_err(|e| match e {
+                winit::error::ExternalError::NotSupported(_) => {
+                    "Setting the cursor position is not supported on this platform"
+                        .to_string()
+                        .into()
+                }
+                e => format!("Error setting the cursor position: {e}").into(),
+            })
+    }
+
+    fn current_monitor(&self) -> Option<MonitorInfo> {
+        let winit_window = winit_window_rc_for_window(self)?;
+        let monitor = winit_window.current_monitor()?;
+        Some(MonitorInfo::from_winit(&monitor, winit_window.primary_monitor().as_ref()))
+    }
+
+    fn on_monitor_changed(&self, callback: impl FnMut(&MonitorInfo) + 'static) {
+        with_winit_window_adapter(self, |adapter| adapter.monitor_changed.set_handler(callback));
+    }
+
+    fn on_scale_factor_changed(&self, mut callback: impl FnMut(f32, f32, PhysicalSize) + 'static) {
+        with_winit_window_adapter(self, |adapter| {
+            adapter
+                .scale_factor_changed
+                .set_handler(move |(old, new, size)| callback(*old, *new, *size))
+        });
+    }
+
+    fn on_file_drop_event(&self, callback: impl FnMut(&FileDropEvent) + 'static) {
+        with_winit_window_adapter(self, |adapter| adapter.file_drop_event.set_handler(callback));
+    }
+
+    fn on_touch_event(&self, callback: impl FnMut(&TouchEvent) + 'static) {
+        with_winit_window_adapter(self, |adapter| adapter.touch_event.set_handler(callback));
+    }
+
+    fn on_scroll_event(&self, callback: impl FnMut(&ScrollEvent) + 'static) {
+        with_winit_window_adapter(self, |adapter| adapter.scroll_event.set_handler(callback));
+    }
+
+    fn on_keyboard_event(&self, callback: impl FnMut(&KeyboardEvent) + 'static) {
+        with_winit_window_adapter(self, |adapter| adapter.keyboard_event.set_handler(callback));
+    }
+
+    fn on_focus_changed(&self, callback: impl FnMut(&bool) + 'static) {
+        with_winit_window_adapter(self, |adapter| adapter.focus_changed.set_handler(callback));
+    }
+
+    fn on_focus_traversal(&self, callback: impl FnMut(&FocusTraversal) -> bool + 'static) {
+        with_winit_window_adapter(self, |adapter| adapter.focus_traversal.set_handler(callback));
+    }
+
+    fn graphics_api_info(&self) -> Option<GraphicsApiInfo> {
+        with_winit_window_adapter(self, |adapter| adapter.renderer.graphics_api_info()).flatten()
+    }
 }
 
 impl private::WinitWindowAccessorSealed for i_slint_core::api::Window {}
 
+fn not_a_winit_window_error() -> PlatformError {
+    "The window is not backed by the winit backend".to_string().into()
+}
+
+fn with_winit_window_adapter<T>(
+    window: &i_slint_core::api::Window,
+    callback: impl FnOnce(&WinitWindowAdapter) -> T,
+) -> Option<T> {
+    i_slint_core::window::WindowInner::from_pub(window)
+        .window_adapter()
+        .internal(i_slint_core::InternalToken)
+        .and_then(|wa| wa.as_any().downcast_ref::<WinitWindowAdapter>())
+        .map(callback)
+}
+
 fn winit_window_rc_for_window(
     window: &i_slint_core::api::Window,
 ) -> Option<Rc<winit::window::Window>> {
@@ -0,0 +1,47 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! This module contains the types used to describe the monitors connected to the system.
+
+use i_slint_core::api::{PhysicalPosition, PhysicalSize};
+
+/// Describes a monitor as reported by the windowing system, as returned by [`crate::Backend::available_monitors`]
+/// and [`crate::WinitWindowAccessor::current_monitor`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorInfo {
+    /// The human-readable name of the monitor, if the windowing system provides one.
+    pub name: Option<String>,
+    /// The position of the top-left corner of the monitor on the desktop, in physical pixels.
+    pub position: PhysicalPosition,
+    /// The resolution of the monitor in physical pixels.
+    pub size: PhysicalSize,
+    /// The scale factor windows on this monitor will use, to map logical pixels to physical pixels.
+    pub scale_factor: f32,
+    /// The refresh rate of the monitor in millihertz, if known.
+    pub refresh_rate_millihertz: Option<u32>,
+    /// True if this is the primary monitor of the system.
+    pub is_primary: bool,
+    /// The video modes the monitor supports. Pass one of them to [`crate::FullscreenMode::Exclusive`]
+    /// to switch the monitor to that resolution and refresh rate.
+    pub video_modes: Vec<winit::monitor::VideoMode>,
+}
+
+impl MonitorInfo {
+    pub(crate) fn from_winit(
+        monitor: &winit::monitor::MonitorHandle,
+        primary_monitor: Option<&winit::monitor::MonitorHandle>,
+    ) -> Self {
+        let position = monitor.position();
+        let size = monitor.size();
+        Self {
+            name: monitor.name(),
+            position: PhysicalPosition::new(position.x, position.y),
+            size: PhysicalSize::new(size.width, size.height),
+            scale_factor: monitor.scale_factor() as f32,
+            refresh_rate_millihertz: monitor.refresh_rate_millihertz(),
+            is_primary: primary_monitor.map_or(false, |primary| primary == monitor),
+            video_modes: monitor.video_modes().collect(),
+        }
+    }
+}
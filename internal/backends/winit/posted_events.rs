@@ -0,0 +1,82 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! This module contains functions to post closures to the event loop, to be invoked either right
+//! away or after a delay, that can be canceled before they run.
+
+use crate::event_loop::CustomEvent;
+use crate::SlintUserEvent;
+use i_slint_core::api::EventLoopError;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+
+const PENDING: u8 = 0;
+const INVOKED: u8 = 1;
+const CANCELED: u8 = 2;
+
+/// A handle to a closure posted with [`post_event`] or [`post_event_after`], used to cancel the closure before it's invoked.
+///
+/// Dropping the handle does not cancel the closure.
+#[derive(Clone, Debug)]
+pub struct PostedEventHandle(Arc<AtomicU8>);
+
+impl PostedEventHandle {
+    fn new() -> Self {
+        Self(Arc::new(AtomicU8::new(PENDING)))
+    }
+
+    /// Prevents the closure from being invoked, if it hasn't been invoked yet. Returns true if the
+    /// closure was canceled, and false if it was invoked already or was canceled before.
+    pub fn cancel(&self) -> bool {
+        self.0.compare_exchange(PENDING, CANCELED, Ordering::AcqRel, Ordering::Acquire).is_ok()
+    }
+
+    /// Returns true if the closure has been invoked by the event loop.
+    pub fn has_run(&self) -> bool {
+        self.0.load(Ordering::Acquire) == INVOKED
+    }
+
+    /// Wraps the closure so that it's only invoked if this handle wasn't canceled.
+    fn wrap(&self, event: Box<dyn FnOnce() + Send>) -> Box<dyn FnOnce() + Send> {
+        let state = self.0.clone();
+        Box::new(move || {
+            if state.compare_exchange(PENDING, INVOKED, Ordering::AcqRel, Ordering::Acquire).is_ok()
+            {
+                event()
+            }
+        })
+    }
+}
+
+/// Posts the closure to the Slint event loop, to be invoked from the main thread, and returns a handle
+/// that can be used to cancel it before it runs.
+///
+/// Like [`slint::invoke_from_event_loop()`](i_slint_core::api::invoke_from_event_loop), this function can
+/// be called from any thread.
+pub fn post_event(event: Box<dyn FnOnce() + Send>) -> Result<PostedEventHandle, EventLoopError> {
+    let handle = PostedEventHandle::new();
+    crate::send_event_via_global_event_loop_proxy(SlintUserEvent::CustomEvent {
+        event: CustomEvent::UserEvent(handle.wrap(event)),
+    })?;
+    Ok(handle)
+}
+
+/// Posts the closure to the Slint event loop, to be invoked from the main thread once the specified
+/// delay has elapsed, and returns a handle that can be used to cancel it before it runs.
+///
+/// The delay is measured from the moment the event loop receives the closure. Closures with the same
+/// deadline are invoked in the order they were posted. This function can be called from any thread.
+pub fn post_event_after(
+    delay: core::time::Duration,
+    event: Box<dyn FnOnce() + Send>,
+) -> Result<PostedEventHandle, EventLoopError> {
+    let handle = PostedEventHandle::new();
+    let event = handle.wrap(event);
+    // The Slint timers are processed by the event loop, which wakes up in time for the next one.
+    crate::send_event_via_global_event_loop_proxy(SlintUserEvent::CustomEvent {
+        event: CustomEvent::UserEvent(Box::new(move || {
+            i_slint_core::timers::Timer::single_shot(delay, event)
+        })),
+    })?;
+    Ok(handle)
+}
@@ -17,6 +17,7 @@
 
 pub struct GlutinFemtoVGRenderer {
     renderer: FemtoVGRenderer,
+    swap_interval: Option<crate::SwapInterval>,
 }
 
 impl GlutinFemtoVGRenderer {
@@ -42,6 +43,11 @@ pub fn new(
             })
         })?);
 
+        #[cfg(not(target_arch = "wasm32"))]
+        let swap_interval = opengl_context.swap_interval();
+        #[cfg(target_arch = "wasm32")]
+        let swap_interval = None;
+
         let renderer = FemtoVGRenderer::new(
             #[cfg(not(target_arch = "wasm32"))]
             opengl_context,
@@ -51,7 +57,7 @@ pub fn new(
                 .ok_or_else(|| "FemtoVG Renderer: winit didn't return a canvas")?,
         )?;
 
-        Ok((Box::new(Self { renderer }), winit_window))
+        Ok((Box::new(Self { renderer, swap_interval }), winit_window))
     }
 }
 
@@ -63,4 +69,16 @@ fn render(&self, _window: &i_slint_core::api::Window) -> Result<(), PlatformErro
     fn as_core_renderer(&self) -> &dyn Renderer {
         &self.renderer
     }
+
+    fn graphics_api_info(&self) -> Option<crate::GraphicsApiInfo> {
+        let (version, renderer) = self.renderer.opengl_version_and_renderer().ok()?;
+        let kind = if cfg!(target_arch = "wasm32") {
+            crate::GraphicsApiKind::WebGL
+        } else if version.starts_with("OpenGL ES") {
+            crate::GraphicsApiKind::OpenGLES
+        } else {
+            crate::GraphicsApiKind::OpenGL
+        };
+        Some(crate::GraphicsApiInfo { kind, version, renderer, swap_interval: self.swap_interval })
+    }
 }
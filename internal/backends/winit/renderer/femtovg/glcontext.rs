@@ -4,7 +4,7 @@
 use std::{num::NonZeroU32, rc::Rc};
 
 use glutin::{
-    context::{ContextApi, ContextAttributesBuilder},
+    context::{ContextApi, ContextAttributesBuilder, GlProfile},
     display::GetGlDisplay,
     prelude::*,
     surface::{SurfaceAttributesBuilder, WindowSurface},
@@ -16,6 +16,7 @@ pub struct OpenGLContext {
     context: glutin::context::PossiblyCurrentContext,
     surface: glutin::surface::Surface<glutin::surface::WindowSurface>,
     winit_window: Rc<winit::window::Window>,
+    swap_interval: Option<crate::SwapInterval>,
 }
 
 unsafe impl i_slint_renderer_femtovg::OpenGLInterface for OpenGLContext {
@@ -51,6 +52,11 @@ fn resize(
     fn get_proc_address(&self, name: &std::ffi::CStr) -> *const std::ffi::c_void {
         self.context.display().get_proc_address(name)
     }
+
+    fn surface_size(&self) -> Option<i_slint_core::api::PhysicalSize> {
+        let (width, height) = self.surface.width().zip(self.surface.height())?;
+        Some(i_slint_core::api::PhysicalSize::new(width, height))
+    }
 }
 
 impl OpenGLContext {
@@ -98,20 +104,57 @@ pub fn new_context<T>(
 
         let raw_window_handle = window.as_ref().map(|w| w.raw_window_handle());
 
-        let gles_context_attributes = ContextAttributesBuilder::new()
-            .with_context_api(ContextApi::Gles(Some(glutin::context::Version {
-                major: 2,
-                minor: 0,
-            })))
+        let not_current_gl_context = if let Some(requested_api) =
+            crate::event_loop::requested_opengl_api()
+        {
+            let version = |version: Option<(u8, u8)>| {
+                version.map(|(major, minor)| glutin::context::Version { major, minor })
+            };
+            let context_attributes = match requested_api {
+                crate::RequestedOpenGLApi::OpenGL(requested_version) => {
+                    let builder = ContextAttributesBuilder::new()
+                        .with_context_api(ContextApi::OpenGl(version(requested_version)));
+                    if requested_version.map_or(false, |v| v >= (3, 2)) {
+                        builder.with_profile(GlProfile::Core)
+                    } else {
+                        builder
+                    }
+                }
+                crate::RequestedOpenGLApi::OpenGLES(requested_version) => {
+                    ContextAttributesBuilder::new()
+                        .with_context_api(ContextApi::Gles(version(requested_version)))
+                }
+            }
             .build(raw_window_handle);
 
-        let fallback_context_attributes = ContextAttributesBuilder::new().build(raw_window_handle);
+            unsafe {
+                gl_display.create_context(&gl_config, &context_attributes).map_err(
+                    |glutin_err| {
+                        format!(
+                            "Cannot create the requested {requested_api:?} context: {glutin_err}"
+                        )
+                    },
+                )?
+            }
+        } else {
+            let gles_context_attributes = ContextAttributesBuilder::new()
+                .with_context_api(ContextApi::Gles(Some(glutin::context::Version {
+                    major: 2,
+                    minor: 0,
+                })))
+                .build(raw_window_handle);
+
+            let fallback_context_attributes =
+                ContextAttributesBuilder::new().build(raw_window_handle);
 
-        let not_current_gl_context = unsafe {
-            gl_display
-                .create_context(&gl_config, &gles_context_attributes)
-                .or_else(|_| gl_display.create_context(&gl_config, &fallback_context_attributes))
-                .map_err(|glutin_err| format!("Cannot create OpenGL context: {}", glutin_err))?
+            unsafe {
+                gl_display
+                    .create_context(&gl_config, &gles_context_attributes)
+                    .or_else(|_| {
+                        gl_display.create_context(&gl_config, &fallback_context_attributes)
+                    })
+                    .map_err(|glutin_err| format!("Cannot create OpenGL context: {}", glutin_err))?
+            }
         };
 
         let window = match window {
@@ -183,16 +226,31 @@ pub fn new_context<T>(
             );
         }
 
-        // Try to default to vsync and ignore if the driver doesn't support it.
-        surface
-            .set_swap_interval(
-                &context,
-                glutin::surface::SwapInterval::Wait(NonZeroU32::new(1).unwrap()),
-            )
-            .ok();
+        // Try the requested swap interval first and fall back to not waiting for vsync. Drivers
+        // that don't support changing it at all are not an error.
+        let fallbacks: &[crate::SwapInterval] = match crate::event_loop::swap_interval() {
+            crate::SwapInterval::VSync => {
+                &[crate::SwapInterval::VSync, crate::SwapInterval::Immediate]
+            }
+            crate::SwapInterval::Immediate => &[crate::SwapInterval::Immediate],
+        };
+        let swap_interval = fallbacks.iter().copied().find(|swap_interval| {
+            let glutin_swap_interval = match swap_interval {
+                crate::SwapInterval::Immediate => glutin::surface::SwapInterval::DontWait,
+                crate::SwapInterval::VSync => {
+                    glutin::surface::SwapInterval::Wait(NonZeroU32::new(1).unwrap())
+                }
+            };
+            surface.set_swap_interval(&context, glutin_swap_interval).is_ok()
+        });
 
         let window = Rc::new(window);
 
-        Ok((window.clone(), Self { context, surface, winit_window: window }))
+        Ok((window.clone(), Self { context, surface, winit_window: window, swap_interval }))
+    }
+
+    /// Returns the swap interval that the driver accepted, if any.
+    pub fn swap_interval(&self) -> Option<crate::SwapInterval> {
+        self.swap_interval
     }
 }
@@ -182,6 +182,15 @@ fn as_core_renderer(&self) -> &dyn i_slint_core::renderer::Renderer {
         &self.renderer
     }
 
+    fn graphics_api_info(&self) -> Option<crate::GraphicsApiInfo> {
+        Some(crate::GraphicsApiInfo {
+            kind: crate::GraphicsApiKind::Software,
+            version: String::new(),
+            renderer: "Slint software renderer".into(),
+            swap_interval: None,
+        })
+    }
+
     fn occluded(&self, _: bool) {
         // On X11, the buffer is completely cleared when the window is hidden
         // and the buffer age doesn't respect that, so clean the partial rendering cache
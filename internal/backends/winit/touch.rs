@@ -0,0 +1,207 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! This module tracks the fingers on a touch screen, to deliver touch events to the application
+//! and to synthesize two finger pinch and pan gestures.
+
+use i_slint_core::api::LogicalPosition;
+pub use winit::event::TouchPhase;
+
+/// A touch event, passed to the callback set with [`crate::WinitWindowAccessor::on_touch_event`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum TouchEvent {
+    /// A finger touched, moved on, or was lifted from the screen.
+    ///
+    /// When a finger is lifted while other fingers are still touching the screen, the touch is
+    /// reported as [`TouchPhase::Cancelled`], as the multi-finger gesture was interrupted.
+    Touch {
+        /// Identifies the finger for the duration of the touch.
+        id: u64,
+        /// The phase of the touch.
+        phase: TouchPhase,
+        /// The position of the finger in the window.
+        position: LogicalPosition,
+    },
+    /// Two fingers moved towards or away from each other.
+    Pinch {
+        /// The point between the two fingers.
+        center: LogicalPosition,
+        /// The ratio of the new distance between the fingers to the previous distance.
+        scale: f32,
+    },
+    /// Two fingers moved in the same direction.
+    Pan {
+        /// The horizontal distance the point between the two fingers moved, in logical pixels.
+        delta_x: f32,
+        /// The vertical distance the point between the two fingers moved, in logical pixels.
+        delta_y: f32,
+    },
+}
+
+/// The fingers currently touching a window.
+#[derive(Default)]
+pub(crate) struct TouchState {
+    points: Vec<(u64, LogicalPosition)>,
+    /// The finger that is used to emulate mouse events.
+    primary: Option<u64>,
+}
+
+impl TouchState {
+    /// Records the touch and returns the events to deliver to the application, as well as
+    /// whether the touch belongs to the primary finger, which is used to emulate mouse input.
+    pub(crate) fn process(
+        &mut self,
+        id: u64,
+        phase: TouchPhase,
+        position: LogicalPosition,
+    ) -> (bool, Vec<TouchEvent>) {
+        let index = self.points.iter().position(|(point_id, _)| *point_id == id);
+        let is_primary = self.primary == Some(id);
+
+        match (phase, index) {
+            (TouchPhase::Started, _) => {
+                if let Some(index) = index {
+                    // A start without an end for the same finger, don't leave the old touch stuck.
+                    self.points.remove(index);
+                }
+                self.points.push((id, position));
+                let is_primary = *self.primary.get_or_insert(id) == id;
+                (is_primary, vec![TouchEvent::Touch { id, phase, position }])
+            }
+            (TouchPhase::Moved, Some(index)) => {
+                let previous = self.two_finger_geometry();
+                self.points[index].1 = position;
+                let mut events = vec![TouchEvent::Touch { id, phase, position }];
+                if let Some(((old_center, old_distance), (new_center, new_distance))) =
+                    previous.zip(self.two_finger_geometry())
+                {
+                    if old_distance > 0. && new_distance != old_distance {
+                        events.push(TouchEvent::Pinch {
+                            center: new_center,
+                            scale: new_distance / old_distance,
+                        });
+                    }
+                    if new_center != old_center {
+                        events.push(TouchEvent::Pan {
+                            delta_x: new_center.x - old_center.x,
+                            delta_y: new_center.y - old_center.y,
+                        });
+                    }
+                }
+                (is_primary, events)
+            }
+            (TouchPhase::Ended | TouchPhase::Cancelled, Some(index)) => {
+                let phase = if self.points.len() > 1 { TouchPhase::Cancelled } else { phase };
+                self.points.remove(index);
+                if is_primary {
+                    self.primary = None;
+                }
+                (is_primary, vec![TouchEvent::Touch { id, phase, position }])
+            }
+            // Events for fingers we don't know about
+            (_, None) => (false, Vec::new()),
+        }
+    }
+
+    /// Returns the center of and the distance between the fingers, if exactly two fingers touch the window.
+    fn two_finger_geometry(&self) -> Option<(LogicalPosition, f32)> {
+        let [(_, a), (_, b)] = self.points.as_slice() else { return None };
+        let center = LogicalPosition::new((a.x + b.x) / 2., (a.y + b.y) / 2.);
+        Some((center, (b.x - a.x).hypot(b.y - a.y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn touch(id: u64, phase: TouchPhase, x: f32, y: f32) -> TouchEvent {
+        TouchEvent::Touch { id, phase, position: LogicalPosition::new(x, y) }
+    }
+
+    #[test]
+    fn single_finger() {
+        let mut state = TouchState::default();
+        let pos = LogicalPosition::new;
+        assert_eq!(
+            state.process(1, TouchPhase::Started, pos(0., 0.)),
+            (true, vec![touch(1, TouchPhase::Started, 0., 0.)])
+        );
+        assert_eq!(
+            state.process(1, TouchPhase::Moved, pos(10., 5.)),
+            (true, vec![touch(1, TouchPhase::Moved, 10., 5.)])
+        );
+        assert_eq!(
+            state.process(1, TouchPhase::Ended, pos(10., 5.)),
+            (true, vec![touch(1, TouchPhase::Ended, 10., 5.)])
+        );
+        // The finger is gone
+        assert_eq!(state.process(1, TouchPhase::Moved, pos(20., 5.)), (false, vec![]));
+        assert_eq!(state.process(2, TouchPhase::Ended, pos(20., 5.)), (false, vec![]));
+
+        // A start without an end replaces the old touch
+        state.process(3, TouchPhase::Started, pos(0., 0.));
+        state.process(3, TouchPhase::Started, pos(5., 5.));
+        assert_eq!(
+            state.process(3, TouchPhase::Ended, pos(5., 5.)),
+            (true, vec![touch(3, TouchPhase::Ended, 5., 5.)])
+        );
+    }
+
+    #[test]
+    fn multi_touch() {
+        let mut state = TouchState::default();
+        let pos = LogicalPosition::new;
+        assert!(state.process(1, TouchPhase::Started, pos(0., 0.)).0);
+        assert_eq!(
+            state.process(2, TouchPhase::Started, pos(10., 0.)),
+            (false, vec![touch(2, TouchPhase::Started, 10., 0.)])
+        );
+
+        // Moving apart is a pinch, and the center moves as well
+        assert_eq!(
+            state.process(2, TouchPhase::Moved, pos(20., 0.)),
+            (
+                false,
+                vec![
+                    touch(2, TouchPhase::Moved, 20., 0.),
+                    TouchEvent::Pinch { center: pos(10., 0.), scale: 2. },
+                    TouchEvent::Pan { delta_x: 5., delta_y: 0. },
+                ]
+            )
+        );
+        // Same distance, the center moves
+        assert_eq!(
+            state.process(2, TouchPhase::Moved, pos(0., 20.)),
+            (
+                false,
+                vec![
+                    touch(2, TouchPhase::Moved, 0., 20.),
+                    TouchEvent::Pan { delta_x: -10., delta_y: 10. },
+                ]
+            )
+        );
+
+        // Lifting a finger while another one touches the screen cancels the gesture
+        assert_eq!(
+            state.process(1, TouchPhase::Ended, pos(0., 0.)),
+            (true, vec![touch(1, TouchPhase::Cancelled, 0., 0.)])
+        );
+        // With a single finger left, there are no gestures
+        assert_eq!(
+            state.process(2, TouchPhase::Moved, pos(0., 30.)),
+            (false, vec![touch(2, TouchPhase::Moved, 0., 30.)])
+        );
+        // The next finger becomes the primary one
+        assert!(state.process(3, TouchPhase::Started, pos(50., 50.)).0);
+        assert_eq!(
+            state.process(2, TouchPhase::Ended, pos(0., 30.)),
+            (false, vec![touch(2, TouchPhase::Cancelled, 0., 30.)])
+        );
+        assert_eq!(
+            state.process(3, TouchPhase::Ended, pos(50., 50.)),
+            (true, vec![touch(3, TouchPhase::Ended, 50., 50.)])
+        );
+    }
+}
@@ -5,9 +5,7 @@
 
 // cspell:ignore accesskit borderless corelib nesw webgl winit winsys xlib
 
-use core::cell::Cell;
-#[cfg(target_arch = "wasm32")]
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 use std::rc::Rc;
 #[cfg(target_arch = "wasm32")]
@@ -95,6 +93,40 @@ fn icon_to_winit(icon: corelib::graphics::Image) -> Option<winit::window::Icon>
     winit::window::Icon::from_rgba(rgba_pixels, pixel_buffer.width(), pixel_buffer.height()).ok()
 }
 
+fn mouse_cursor_to_winit(cursor: MouseCursor) -> winit::window::CursorIcon {
+    match cursor {
+        MouseCursor::Default => winit::window::CursorIcon::Default,
+        MouseCursor::None => winit::window::CursorIcon::Default,
+        MouseCursor::Help => winit::window::CursorIcon::Help,
+        MouseCursor::Pointer => winit::window::CursorIcon::Pointer,
+        MouseCursor::Progress => winit::window::CursorIcon::Progress,
+        MouseCursor::Wait => winit::window::CursorIcon::Wait,
+        MouseCursor::Crosshair => winit::window::CursorIcon::Crosshair,
+        MouseCursor::Text => winit::window::CursorIcon::Text,
+        MouseCursor::Alias => winit::window::CursorIcon::Alias,
+        MouseCursor::Copy => winit::window::CursorIcon::Copy,
+        MouseCursor::Move => winit::window::CursorIcon::Move,
+        MouseCursor::NoDrop => winit::window::CursorIcon::NoDrop,
+        MouseCursor::NotAllowed => winit::window::CursorIcon::NotAllowed,
+        MouseCursor::Grab => winit::window::CursorIcon::Grab,
+        MouseCursor::Grabbing => winit::window::CursorIcon::Grabbing,
+        MouseCursor::ColResize => winit::window::CursorIcon::ColResize,
+        MouseCursor::RowResize => winit::window::CursorIcon::RowResize,
+        MouseCursor::NResize => winit::window::CursorIcon::NResize,
+        MouseCursor::EResize => winit::window::CursorIcon::EResize,
+        MouseCursor::SResize => winit::window::CursorIcon::SResize,
+        MouseCursor::WResize => winit::window::CursorIcon::WResize,
+        MouseCursor::NeResize => winit::window::CursorIcon::NeResize,
+        MouseCursor::NwResize => winit::window::CursorIcon::NwResize,
+        MouseCursor::SeResize => winit::window::CursorIcon::SeResize,
+        MouseCursor::SwResize => winit::window::CursorIcon::SwResize,
+        MouseCursor::EwResize => winit::window::CursorIcon::EwResize,
+        MouseCursor::NsResize => winit::window::CursorIcon::NsResize,
+        MouseCursor::NeswResize => winit::window::CursorIcon::NeswResize,
+        MouseCursor::NwseResize => winit::window::CursorIcon::NwseResize,
+    }
+}
+
 fn window_is_resizable(
     min_size: Option<corelib::api::LogicalSize>,
     max_size: Option<corelib::api::LogicalSize>,
@@ -110,6 +142,21 @@ fn window_is_resizable(
     }
 }
 
+/// Combines two optional size constraints component-wise with `combine`, or returns whichever is set.
+fn combine_size_constraints(
+    a: Option<corelib::api::LogicalSize>,
+    b: Option<corelib::api::LogicalSize>,
+    combine: fn(f32, f32) -> f32,
+) -> Option<corelib::api::LogicalSize> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(corelib::api::LogicalSize::new(
+            combine(a.width, b.width),
+            combine(a.height, b.height),
+        )),
+        (a, b) => a.or(b),
+    }
+}
+
 /// GraphicsWindow is an implementation of the [WindowAdapter][`crate::eventloop::WindowAdapter`] trait. This is
 /// typically instantiated by entry factory functions of the different graphics back ends.
 pub struct WinitWindowAdapter {
@@ -117,10 +164,57 @@ pub struct WinitWindowAdapter {
     #[cfg(target_arch = "wasm32")]
     self_weak: Weak<Self>,
     pending_redraw: Cell<bool>,
+    /// When the last frame was rendered, used to throttle redraws to the frame interval set on the backend.
+    last_frame_time: Cell<Option<corelib::animations::Instant>>,
+    /// True while the window is fully hidden by other windows or minimized, as reported by winit. Redraw
+    /// requests are held back in the meanwhile.
+    occluded: Cell<bool>,
+    /// Set when a redraw was requested sooner than the frame interval allows. It's the time at which
+    /// the redraw is forwarded to winit.
+    deferred_redraw: Cell<Option<corelib::animations::Instant>>,
     dark_color_scheme: OnceCell<Pin<Box<Property<bool>>>>,
     constraints: Cell<corelib::window::LayoutConstraints>,
     shown: Cell<bool>,
     window_level: Cell<winit::window::WindowLevel>,
+    /// Window level set via the `WinitWindowAccessor`, taking precedence over the `always-on-top` property.
+    window_level_override: Cell<Option<winit::window::WindowLevel>>,
+    /// Icon set via [`crate::WinitWindowAccessor::set_window_icon`]. Takes precedence over the `icon` property.
+    window_icon: RefCell<Option<winit::window::Icon>>,
+    /// Title set via [`crate::WinitWindowAccessor::set_window_title`]. Takes precedence over the `title` property.
+    title_override: RefCell<Option<String>>,
+    fullscreen_mode: RefCell<crate::FullscreenMode>,
+    /// The outer position and inner size of the window before entering fullscreen, restored when leaving it.
+    windowed_geometry: Cell<Option<(winit::dpi::PhysicalPosition<i32>, PhysicalSize)>>,
+    /// Set when leaving fullscreen, until `windowed_geometry` was restored in `restore_windowed_geometry()`.
+    restore_windowed_geometry: Cell<bool>,
+    /// Size limits set via the `WinitWindowAccessor`, in addition to the layout constraints.
+    min_inner_size: Cell<Option<corelib::api::LogicalSize>>,
+    max_inner_size: Cell<Option<corelib::api::LogicalSize>>,
+    /// Cursor set via the `WinitWindowAccessor`, taking precedence over the `mouse-cursor` of the items.
+    cursor_icon_override: Cell<Option<winit::window::CursorIcon>>,
+    /// The item's cursor as last requested by Slint, restored when the override is removed.
+    current_mouse_cursor: Cell<MouseCursor>,
+    cursor_hidden: Cell<bool>,
+
+    /// Invoked with the old scale factor, the new scale factor and the new size of the window.
+    pub(crate) scale_factor_changed: corelib::Callback<(f32, f32, PhysicalSize)>,
+    pub(crate) file_drop_event: corelib::Callback<crate::FileDropEvent>,
+    pub(crate) scroll_event: corelib::Callback<crate::ScrollEvent>,
+    pub(crate) keyboard_event: corelib::Callback<crate::KeyboardEvent>,
+    pub(crate) focus_changed: corelib::Callback<bool>,
+    pub(crate) monitor_changed: corelib::Callback<crate::MonitorInfo>,
+    /// The monitor the window was last seen on, to detect when it moves to a different one.
+    last_monitor: RefCell<Option<winit::monitor::MonitorHandle>>,
+    /// Returns true if the application handled the focus traversal itself.
+    pub(crate) focus_traversal: corelib::Callback<crate::FocusTraversal, bool>,
+    /// winit reports dragged files one by one, collect them to deliver them in one event.
+    pending_hovered_files: RefCell<Vec<std::path::PathBuf>>,
+    pending_dropped_files: RefCell<Vec<std::path::PathBuf>>,
+    file_hover_active: Cell<bool>,
+    pub(crate) touch_event: corelib::Callback<crate::TouchEvent>,
+    /// The last position of the mouse cursor in the window, or `None` if it left the window.
+    pub(crate) cursor_position: Cell<Option<corelib::api::LogicalPosition>>,
+    pub(crate) touch_state: RefCell<crate::touch::TouchState>,
 
     pub(crate) renderer: Box<dyn WinitCompatibleRenderer>,
     /// We cache the size because winit_window.inner_size() can return different value between calls (eg, on X11)
@@ -150,10 +244,38 @@ pub(crate) fn new(
             #[cfg(target_arch = "wasm32")]
             self_weak: self_weak.clone(),
             pending_redraw: Default::default(),
+            last_frame_time: Default::default(),
+            occluded: Default::default(),
+            deferred_redraw: Default::default(),
             dark_color_scheme: Default::default(),
             constraints: Default::default(),
             shown: Default::default(),
             window_level: Default::default(),
+            window_level_override: Default::default(),
+            window_icon: Default::default(),
+            title_override: Default::default(),
+            fullscreen_mode: Default::default(),
+            windowed_geometry: Default::default(),
+            restore_windowed_geometry: Default::default(),
+            min_inner_size: Default::default(),
+            max_inner_size: Default::default(),
+            cursor_icon_override: Default::default(),
+            current_mouse_cursor: Cell::new(MouseCursor::Default),
+            cursor_hidden: Default::default(),
+            scale_factor_changed: Default::default(),
+            file_drop_event: Default::default(),
+            scroll_event: Default::default(),
+            keyboard_event: Default::default(),
+            focus_changed: Default::default(),
+            monitor_changed: Default::default(),
+            last_monitor: RefCell::new(winit_window.current_monitor()),
+            focus_traversal: Default::default(),
+            pending_hovered_files: Default::default(),
+            pending_dropped_files: Default::default(),
+            file_hover_active: Default::default(),
+            touch_event: Default::default(),
+            cursor_position: Default::default(),
+            touch_state: Default::default(),
             winit_window: winit_window.clone(),
             size: Default::default(),
             has_explicit_size: Default::default(),
@@ -228,6 +350,10 @@ pub fn draw(&self) -> Result<(), PlatformError> {
         }
 
         self.pending_redraw.set(false);
+        self.deferred_redraw.set(None);
+        if crate::event_loop::min_frame_interval().is_some() {
+            self.last_frame_time.set(Some(corelib::animations::Instant::now()));
+        }
 
         let renderer = self.renderer();
         renderer.render(self.window())?;
@@ -235,6 +361,33 @@ pub fn draw(&self) -> Result<(), PlatformError> {
         Ok(())
     }
 
+    /// Called when the window becomes occluded or visible again. While occluded, redraw requests are
+    /// only recorded, and the pending redraw is issued when the window becomes visible. Animations are
+    /// driven by the elapsed time, so they continue from their current state instead of resuming where
+    /// they were paused.
+    pub(crate) fn set_occluded(&self, occluded: bool) {
+        self.renderer.occluded(occluded);
+        if self.occluded.replace(occluded) && !occluded && self.pending_redraw.replace(false) {
+            self.request_redraw();
+        }
+    }
+
+    /// Forwards a redraw request that was held back by the frame interval to winit, once it's due.
+    /// Returns the time remaining until the deferred redraw is due, if it isn't yet.
+    pub(crate) fn flush_deferred_redraw(
+        &self,
+        now: corelib::animations::Instant,
+    ) -> Option<core::time::Duration> {
+        let due = self.deferred_redraw.get()?;
+        if due <= now {
+            self.deferred_redraw.set(None);
+            self.winit_window.request_redraw();
+            None
+        } else {
+            Some(due - now)
+        }
+    }
+
     fn with_window_handle(&self, callback: &mut dyn FnMut(&winit::window::Window)) {
         callback(&self.winit_window());
     }
@@ -295,6 +448,346 @@ pub fn resize_event(&self, size: winit::dpi::PhysicalSize<u32>) -> Result<(), Pl
         Ok(())
     }
 
+    pub fn set_window_icon(
+        &self,
+        icon: SharedPixelBuffer<Rgba8Pixel>,
+    ) -> Result<(), PlatformError> {
+        #[cfg(target_arch = "wasm32")]
+        let _ = icon;
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            // winit's Icon::from_rgba accepts empty buffers, but the platform code may panic on them later.
+            if icon.width() == 0 || icon.height() == 0 {
+                return Err(format!(
+                    "Cannot use an image of size {}x{} as window icon",
+                    icon.width(),
+                    icon.height()
+                )
+                .into());
+            }
+            let icon = winit::window::Icon::from_rgba(
+                icon.as_bytes().to_vec(),
+                icon.width(),
+                icon.height(),
+            )
+            .map_err(|e| format!("Invalid window icon: {e}"))?;
+            *self.window_icon.borrow_mut() = Some(icon.clone());
+            self.winit_window().set_window_icon(Some(icon));
+        }
+        Ok(())
+    }
+
+    pub fn fullscreen_mode(&self) -> crate::FullscreenMode {
+        self.fullscreen_mode.borrow().clone()
+    }
+
+    pub fn set_fullscreen_mode(&self, mode: crate::FullscreenMode) -> Result<(), PlatformError> {
+        let winit_window = self.winit_window();
+
+        let winit_fullscreen = match &mode {
+            crate::FullscreenMode::Windowed => None,
+            // On wasm32, winit maps this to the Fullscreen API of the canvas element.
+            crate::FullscreenMode::Borderless => {
+                Some(winit::window::Fullscreen::Borderless(winit_window.current_monitor()))
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            crate::FullscreenMode::Exclusive(video_mode) => {
+                let monitor = video_mode.monitor();
+                let is_supported = winit_window.available_monitors().any(|m| m == monitor)
+                    && monitor.video_modes().any(|m| &m == video_mode);
+                if !is_supported {
+                    return Err(format!(
+                        "Video mode {video_mode} is not supported by any connected monitor"
+                    )
+                    .into());
+                }
+                Some(winit::window::Fullscreen::Exclusive(video_mode.clone()))
+            }
+            #[cfg(target_arch = "wasm32")]
+            crate::FullscreenMode::Exclusive(_) => {
+                return Err("Exclusive fullscreen is not supported on the web".to_string().into())
+            }
+        };
+
+        // Compare with the requested mode rather than `winit_window.fullscreen()`, which may still
+        // report the previous state while a transition is in progress.
+        let was_windowed = *self.fullscreen_mode.borrow() == crate::FullscreenMode::Windowed;
+        if was_windowed && winit_fullscreen.is_some() {
+            // If the window is entering fullscreen again before the geometry from the last time
+            // was restored, keep that geometry instead of saving the fullscreen one.
+            if !self.restore_windowed_geometry.replace(false) {
+                let position = winit_window.outer_position().unwrap_or_default();
+                self.windowed_geometry.set(Some((position, self.size.get())));
+            }
+        } else if !was_windowed && winit_fullscreen.is_none() {
+            // Leaving fullscreen is asynchronous on most platforms, and the window manager applies
+            // its own geometry when it's done. So the saved geometry is restored when the resize or
+            // move event for leaving fullscreen arrives.
+            self.restore_windowed_geometry.set(true);
+        }
+
+        *self.fullscreen_mode.borrow_mut() = mode;
+        winit_window.set_fullscreen(winit_fullscreen.clone());
+
+        if !was_windowed && winit_fullscreen.is_none() {
+            // Apply the size constraints that changed while in fullscreen.
+            self.apply_size_constraints(&winit_window);
+        }
+
+        // Keep the `fullscreen` state of the Slint window in sync. This calls update_window_properties(),
+        // which compares with `self.fullscreen_mode` that is already updated, so it doesn't call this
+        // function again.
+        let runtime_window = WindowInner::from_pub(self.window());
+        if runtime_window.fullscreen() != winit_fullscreen.is_some() {
+            runtime_window.set_fullscreen(winit_fullscreen.is_some());
+        }
+
+        Ok(())
+    }
+
+    /// Restores the outer position and inner size the window had before entering fullscreen, once the window
+    /// has left fullscreen. Called for every resize and move event.
+    pub(crate) fn restore_windowed_geometry(&self) -> Result<(), PlatformError> {
+        let winit_window = self.winit_window();
+        if !self.restore_windowed_geometry.get() || winit_window.fullscreen().is_some() {
+            return Ok(());
+        }
+        self.restore_windowed_geometry.set(false);
+        if let Some((position, size)) = self.windowed_geometry.take() {
+            winit_window.set_outer_position(position);
+            self.resize_window(winit::dpi::PhysicalSize::new(size.width, size.height).into())?;
+        }
+        Ok(())
+    }
+
+    pub fn set_min_inner_size(&self, size: Option<corelib::api::WindowSize>) {
+        let scale_factor = self.window().scale_factor();
+        self.min_inner_size.set(size.map(|size| size.to_logical(scale_factor)));
+        // While in fullscreen, the limit is applied when switching back to windowed mode.
+        if *self.fullscreen_mode.borrow() == crate::FullscreenMode::Windowed {
+            self.apply_size_constraints(&self.winit_window);
+        }
+    }
+
+    pub fn set_max_inner_size(&self, size: Option<corelib::api::WindowSize>) {
+        let scale_factor = self.window().scale_factor();
+        self.max_inner_size.set(size.map(|size| size.to_logical(scale_factor)));
+        // While in fullscreen, the limit is applied when switching back to windowed mode.
+        if *self.fullscreen_mode.borrow() == crate::FullscreenMode::Windowed {
+            self.apply_size_constraints(&self.winit_window);
+        }
+    }
+
+    /// Applies the minimum and maximum size from the layout constraints, further restricted by the sizes
+    /// set with `set_min_inner_size` and `set_max_inner_size`, to the winit window.
+    fn apply_size_constraints(&self, winit_window: &winit::window::Window) {
+        let constraints = self.constraints.get();
+        let min_size =
+            combine_size_constraints(constraints.min, self.min_inner_size.get(), f32::max);
+        let max_size =
+            combine_size_constraints(constraints.max, self.max_inner_size.get(), f32::min);
+
+        // Use our scale factor instead of winit's logical size to take a scale factor override into account.
+        let sf = self.window().scale_factor();
+
+        let into_size = |s: corelib::api::LogicalSize| -> winit::dpi::PhysicalSize<f32> {
+            winit::dpi::LogicalSize::new(s.width, s.height).to_physical(sf as f64)
+        };
+
+        let resizable = window_is_resizable(min_size, max_size);
+        // we must call set_resizable before setting the min and max size otherwise setting the min and max size don't work on X11
+        winit_window.set_resizable(resizable);
+        let winit_min_inner = min_size.map(into_size);
+        winit_window.set_min_inner_size(winit_min_inner);
+        let winit_max_inner = max_size.map(into_size);
+        winit_window.set_max_inner_size(winit_max_inner);
+
+        adjust_window_size_to_satisfy_constraints(self, winit_min_inner, winit_max_inner);
+    }
+
+    pub fn set_cursor_icon(&self, icon: Option<winit::window::CursorIcon>) {
+        self.cursor_icon_override.set(icon);
+        self.update_cursor();
+    }
+
+    pub fn set_cursor_visible(&self, visible: bool) {
+        self.cursor_hidden.set(!visible);
+        self.update_cursor();
+    }
+
+    /// Applies the current cursor to the winit window. On wasm32, winit maps this to the CSS
+    /// `cursor` property of the canvas element.
+    fn update_cursor(&self) {
+        let cursor = self.current_mouse_cursor.get();
+        let winit_cursor =
+            self.cursor_icon_override.get().unwrap_or_else(|| mouse_cursor_to_winit(cursor));
+        let visible = !self.cursor_hidden.get()
+            && (cursor != MouseCursor::None || self.cursor_icon_override.get().is_some());
+        self.with_window_handle(&mut |winit_window| {
+            winit_window.set_cursor_visible(visible);
+            winit_window.set_cursor_icon(winit_cursor);
+        });
+    }
+
+    /// Converts the position to physical coordinates and moves it onto a monitor if the window
+    /// wouldn't be visible on any, for example when restoring a position saved with a monitor that
+    /// is not connected anymore. Logical positions are converted with the scale factor of the
+    /// monitor they're on.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn position_on_visible_monitor(
+        &self,
+        position: &corelib::api::WindowPosition,
+    ) -> winit::dpi::PhysicalPosition<i32> {
+        let winit_window = self.winit_window();
+        let monitors: Vec<_> = winit_window.available_monitors().collect();
+
+        let position = match position {
+            corelib::api::WindowPosition::Physical(pos) => {
+                winit::dpi::PhysicalPosition::new(pos.x, pos.y)
+            }
+            corelib::api::WindowPosition::Logical(pos) => {
+                let pos = winit::dpi::LogicalPosition::new(pos.x as f64, pos.y as f64);
+                let scale_factor = monitors
+                    .iter()
+                    .find(|monitor| {
+                        let scale_factor = monitor.scale_factor();
+                        let origin = monitor.position().to_logical::<f64>(scale_factor);
+                        let size = monitor.size().to_logical::<f64>(scale_factor);
+                        (origin.x..origin.x + size.width).contains(&pos.x)
+                            && (origin.y..origin.y + size.height).contains(&pos.y)
+                    })
+                    .map_or(self.window().scale_factor() as f64, |monitor| monitor.scale_factor());
+                pos.to_physical(scale_factor)
+            }
+        };
+
+        let size = winit_window.outer_size();
+        let overlaps = |monitor: &winit::monitor::MonitorHandle| {
+            let (origin, monitor_size) = (monitor.position(), monitor.size());
+            position.x < origin.x + monitor_size.width as i32
+                && position.x + size.width as i32 > origin.x
+                && position.y < origin.y + monitor_size.height as i32
+                && position.y + size.height as i32 > origin.y
+        };
+        if monitors.is_empty() || monitors.iter().any(overlaps) {
+            return position;
+        }
+
+        // Clamp onto the monitor closest to the requested position, aligning the top-left corner
+        // if the window is larger than the monitor.
+        let clamp = |value: i32, origin: i32, monitor_extent: u32, window_extent: u32| {
+            value.min(origin + monitor_extent as i32 - window_extent as i32).max(origin)
+        };
+        let clamped = |monitor: &winit::monitor::MonitorHandle| {
+            let (origin, monitor_size) = (monitor.position(), monitor.size());
+            winit::dpi::PhysicalPosition::new(
+                clamp(position.x, origin.x, monitor_size.width, size.width),
+                clamp(position.y, origin.y, monitor_size.height, size.height),
+            )
+        };
+        monitors
+            .iter()
+            .map(clamped)
+            .min_by_key(|clamped| {
+                let (dx, dy) = ((clamped.x - position.x) as i64, (clamped.y - position.y) as i64);
+                dx * dx + dy * dy
+            })
+            .unwrap_or(position)
+    }
+
+    /// Invokes the monitor changed callback if the window is on a different monitor than when this
+    /// was called last. Called when the window was moved or its scale factor changed.
+    pub(crate) fn check_monitor_changed(&self) {
+        let winit_window = self.winit_window();
+        let Some(monitor) = winit_window.current_monitor() else { return };
+        let previous = self.last_monitor.replace(Some(monitor.clone()));
+        if previous.as_ref() != Some(&monitor) {
+            let primary_monitor = winit_window.primary_monitor();
+            self.monitor_changed
+                .call(&crate::MonitorInfo::from_winit(&monitor, primary_monitor.as_ref()));
+        }
+    }
+
+    pub fn set_title(&self, title: Option<&str>) {
+        *self.title_override.borrow_mut() = title.map(Into::into);
+        self.apply_title();
+    }
+
+    pub fn title(&self) -> String {
+        self.title_override.borrow().clone().unwrap_or_else(|| {
+            WindowInner::from_pub(self.window())
+                .window_item()
+                .map(|window_item| window_item.as_pin_ref().title().into())
+                .unwrap_or_default()
+        })
+    }
+
+    fn apply_title(&self) {
+        let title = self.title();
+        self.winit_window().set_title(&title);
+        // winit doesn't show the title anywhere in the browser, so use it for the document when
+        // it was set explicitly. The `title` property alone shouldn't replace the page's title.
+        #[cfg(target_arch = "wasm32")]
+        if self.title_override.borrow().is_some() {
+            if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+                document.set_title(&title);
+            }
+        }
+    }
+
+    pub fn set_window_level(&self, level: Option<winit::window::WindowLevel>) {
+        self.window_level_override.set(level);
+        let always_on_top = WindowInner::from_pub(self.window())
+            .window_item()
+            .map_or(false, |window_item| window_item.as_pin_ref().always_on_top());
+        self.apply_window_level(level.unwrap_or(if always_on_top {
+            winit::window::WindowLevel::AlwaysOnTop
+        } else {
+            winit::window::WindowLevel::Normal
+        }));
+    }
+
+    fn apply_window_level(&self, level: winit::window::WindowLevel) {
+        // Only change the window level if it changes, to avoid https://github.com/slint-ui/slint/issues/3280
+        // (Ubuntu 20.04's window manager always bringing the window to the front on x11)
+        // Window levels have no meaning in the browser.
+        if self.window_level.replace(level) != level && cfg!(not(target_arch = "wasm32")) {
+            self.winit_window.set_window_level(level);
+        }
+    }
+
+    pub(crate) fn file_hovered(&self, path: std::path::PathBuf) {
+        self.pending_hovered_files.borrow_mut().push(path);
+    }
+
+    pub(crate) fn file_dropped(&self, path: std::path::PathBuf) {
+        self.pending_dropped_files.borrow_mut().push(path);
+    }
+
+    pub(crate) fn file_hover_cancelled(&self, position: corelib::api::LogicalPosition) {
+        self.dispatch_pending_file_drop_events(position);
+        if self.file_hover_active.replace(false) {
+            self.file_drop_event.call(&crate::FileDropEvent::HoverCancelled);
+        }
+    }
+
+    /// Delivers the files collected from winit's `HoveredFile` and `DroppedFile` events.
+    pub(crate) fn dispatch_pending_file_drop_events(
+        &self,
+        position: corelib::api::LogicalPosition,
+    ) {
+        let hovered = core::mem::take(&mut *self.pending_hovered_files.borrow_mut());
+        if !hovered.is_empty() {
+            self.file_hover_active.set(true);
+            self.file_drop_event.call(&crate::FileDropEvent::Hovered { paths: hovered, position });
+        }
+        let dropped = core::mem::take(&mut *self.pending_dropped_files.borrow_mut());
+        if !dropped.is_empty() {
+            self.file_hover_active.set(false);
+            self.file_drop_event.call(&crate::FileDropEvent::Dropped { paths: dropped, position });
+        }
+    }
+
     pub fn set_dark_color_scheme(&self, dark_mode: bool) {
         self.dark_color_scheme
             .get_or_init(|| Box::pin(Property::new(false)))
@@ -406,7 +899,10 @@ fn position(&self) -> Option<corelib::api::PhysicalPosition> {
     }
 
     fn set_position(&self, position: corelib::api::WindowPosition) {
-        self.winit_window().set_outer_position(position_to_winit(&position))
+        #[cfg(target_arch = "wasm32")]
+        self.winit_window().set_outer_position(position_to_winit(&position));
+        #[cfg(not(target_arch = "wasm32"))]
+        self.winit_window().set_outer_position(self.position_on_visible_monitor(&position));
     }
 
     fn set_size(&self, size: corelib::api::WindowSize) {
@@ -421,7 +917,23 @@ fn size(&self) -> corelib::api::PhysicalSize {
 
     fn request_redraw(&self) {
         if !self.pending_redraw.replace(true) {
-            self.winit_window.request_redraw()
+            // Rendering into an occluded window is wasted, so wait until it's visible again.
+            // Redraws requested by the windowing system itself still go through `RedrawRequested`.
+            if self.occluded.get() {
+                return;
+            }
+
+            // Coalesce redraws that come in sooner than the frame interval allows into one at the next
+            // permitted time, instead of dropping them. The event loop forwards them when they're due.
+            let next_frame_time = crate::event_loop::min_frame_interval()
+                .zip(self.last_frame_time.get())
+                .map(|(interval, last_frame_time)| last_frame_time + interval);
+            match next_frame_time {
+                Some(next_frame_time) if next_frame_time > corelib::animations::Instant::now() => {
+                    self.deferred_redraw.set(Some(next_frame_time))
+                }
+                _ => self.winit_window.request_redraw(),
+            }
         }
     }
 
@@ -441,20 +953,23 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
 
         let mut must_resize = false;
 
-        winit_window.set_window_icon(icon_to_winit(window_item.icon()));
-        winit_window.set_title(&properties.title());
+        let window_icon =
+            self.window_icon.borrow().clone().or_else(|| icon_to_winit(window_item.icon()));
+        winit_window.set_window_icon(window_icon);
+        match self.title_override.borrow().as_ref() {
+            Some(title) => winit_window.set_title(title),
+            None => winit_window.set_title(&properties.title()),
+        }
         winit_window
             .set_decorations(!window_item.no_frame() || winit_window.fullscreen().is_some());
-        let new_window_level = if window_item.always_on_top() {
-            winit::window::WindowLevel::AlwaysOnTop
-        } else {
-            winit::window::WindowLevel::Normal
-        };
-        // Only change the window level if it changes, to avoid https://github.com/slint-ui/slint/issues/3280
-        // (Ubuntu 20.04's window manager always bringing the window to the front on x11)
-        if self.window_level.replace(new_window_level) != new_window_level {
-            winit_window.set_window_level(new_window_level);
-        }
+        let new_window_level = self.window_level_override.get().unwrap_or_else(|| {
+            if window_item.always_on_top() {
+                winit::window::WindowLevel::AlwaysOnTop
+            } else {
+                winit::window::WindowLevel::Normal
+            }
+        });
+        self.apply_window_level(new_window_level);
 
         if width <= 0. || height <= 0. {
             must_resize = true;
@@ -495,21 +1010,17 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
         }
 
         self.with_window_handle(&mut |winit_window| {
-            if properties.fullscreen() {
-                if winit_window.fullscreen().is_none() {
-                    winit_window.set_fullscreen(Some(winit::window::Fullscreen::Borderless(None)));
-                }
-            } else {
-                if winit_window.fullscreen().is_some() {
-                    winit_window.set_fullscreen(None);
-                }
-            }
-
-            // If we're in fullscreen state, don't try to resize the window but maintain the surface
-            // size we've been assigned to from the windowing system. Weston/Wayland don't like it
-            // when we create a surface that's bigger than the screen due to constraints (#532).
-            if winit_window.fullscreen().is_some() {
-                return;
+            // Compare with the requested mode, as winit may still report the previous state while
+            // a transition is in progress.
+            let is_fullscreen = *self.fullscreen_mode.borrow() != crate::FullscreenMode::Windowed;
+            if properties.fullscreen() != is_fullscreen {
+                // TODO: don't ignore error, propgate to caller
+                self.set_fullscreen_mode(if properties.fullscreen() {
+                    crate::FullscreenMode::Borderless
+                } else {
+                    crate::FullscreenMode::Windowed
+                })
+                .ok();
             }
 
             let new_constraints = properties.layout_constraints();
@@ -517,24 +1028,18 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
                 return;
             }
 
+            // Remember the constraints even in fullscreen, so that set_fullscreen_mode() applies the
+            // current ones when switching back to windowed mode.
             self.constraints.set(new_constraints);
 
-            // Use our scale factor instead of winit's logical size to take a scale factor override into account.
-            let sf = self.window().scale_factor();
-
-            let into_size = |s: corelib::api::LogicalSize| -> winit::dpi::PhysicalSize<f32> {
-                winit::dpi::LogicalSize::new(s.width, s.height).to_physical(sf as f64)
-            };
-
-            let resizable = window_is_resizable(new_constraints.min, new_constraints.max);
-            // we must call set_resizable before setting the min and max size otherwise setting the min and max size don't work on X11
-            winit_window.set_resizable(resizable);
-            let winit_min_inner = new_constraints.min.map(into_size);
-            winit_window.set_min_inner_size(winit_min_inner);
-            let winit_max_inner = new_constraints.max.map(into_size);
-            winit_window.set_max_inner_size(winit_max_inner);
+            // If we're in fullscreen state, don't try to resize the window but maintain the surface
+            // size we've been assigned to from the windowing system. Weston/Wayland don't like it
+            // when we create a surface that's bigger than the screen due to constraints (#532).
+            if winit_window.fullscreen().is_some() {
+                return;
+            }
 
-            adjust_window_size_to_satisfy_constraints(self, winit_min_inner, winit_max_inner);
+            self.apply_size_constraints(winit_window);
 
             // Auto-resize to the preferred size if users (SlintPad) requests it
             #[cfg(target_arch = "wasm32")]
@@ -566,41 +1071,8 @@ fn internal(&self, _: corelib::InternalToken) -> Option<&dyn WindowAdapterIntern
 
 impl WindowAdapterInternal for WinitWindowAdapter {
     fn set_mouse_cursor(&self, cursor: MouseCursor) {
-        let winit_cursor = match cursor {
-            MouseCursor::Default => winit::window::CursorIcon::Default,
-            MouseCursor::None => winit::window::CursorIcon::Default,
-            MouseCursor::Help => winit::window::CursorIcon::Help,
-            MouseCursor::Pointer => winit::window::CursorIcon::Pointer,
-            MouseCursor::Progress => winit::window::CursorIcon::Progress,
-            MouseCursor::Wait => winit::window::CursorIcon::Wait,
-            MouseCursor::Crosshair => winit::window::CursorIcon::Crosshair,
-            MouseCursor::Text => winit::window::CursorIcon::Text,
-            MouseCursor::Alias => winit::window::CursorIcon::Alias,
-            MouseCursor::Copy => winit::window::CursorIcon::Copy,
-            MouseCursor::Move => winit::window::CursorIcon::Move,
-            MouseCursor::NoDrop => winit::window::CursorIcon::NoDrop,
-            MouseCursor::NotAllowed => winit::window::CursorIcon::NotAllowed,
-            MouseCursor::Grab => winit::window::CursorIcon::Grab,
-            MouseCursor::Grabbing => winit::window::CursorIcon::Grabbing,
-            MouseCursor::ColResize => winit::window::CursorIcon::ColResize,
-            MouseCursor::RowResize => winit::window::CursorIcon::RowResize,
-            MouseCursor::NResize => winit::window::CursorIcon::NResize,
-            MouseCursor::EResize => winit::window::CursorIcon::EResize,
-            MouseCursor::SResize => winit::window::CursorIcon::SResize,
-            MouseCursor::WResize => winit::window::CursorIcon::WResize,
-            MouseCursor::NeResize => winit::window::CursorIcon::NeResize,
-            MouseCursor::NwResize => winit::window::CursorIcon::NwResize,
-            MouseCursor::SeResize => winit::window::CursorIcon::SeResize,
-            MouseCursor::SwResize => winit::window::CursorIcon::SwResize,
-            MouseCursor::EwResize => winit::window::CursorIcon::EwResize,
-            MouseCursor::NsResize => winit::window::CursorIcon::NsResize,
-            MouseCursor::NeswResize => winit::window::CursorIcon::NeswResize,
-            MouseCursor::NwseResize => winit::window::CursorIcon::NwseResize,
-        };
-        self.with_window_handle(&mut |winit_window| {
-            winit_window.set_cursor_visible(cursor != MouseCursor::None);
-            winit_window.set_cursor_icon(winit_cursor);
-        });
+        self.current_mouse_cursor.set(cursor);
+        self.update_cursor();
     }
 
     fn input_method_request(&self, request: corelib::window::InputMethodRequest) {
@@ -236,6 +236,16 @@ enum ImageRendering {
                 Pixelated,
             }
 
+            /// This enum specifies how the source image is repeated to fill an [`Image`](elements.md#image) element.
+            enum ImageTiling {
+                /// The source image is not repeated, it is scaled according to the `image-fit` property.
+                None,
+                /// The source image is repeated at its natural size.
+                Repeat,
+                /// The source image is repeated at its natural size, with every other repetition mirrored.
+                Mirror,
+            }
+
             /// This enum is used to define the type of the input field.
             #[non_exhaustive]
             enum InputType {
@@ -95,7 +95,8 @@ fn from(col: RgbaColor<f32>) -> Self {
 }
 
 impl Color {
-    /// Construct a color from an integer encoded as `0xAARRGGBB`
+    /// Construct a color from an integer encoded as `0xAARRGGBB`, with the alpha channel in the most
+    /// significant byte and the blue channel in the least significant byte.
     pub const fn from_argb_encoded(encoded: u32) -> Color {
         Self {
             red: (encoded >> 16) as u8,
@@ -105,7 +106,8 @@ pub const fn from_argb_encoded(encoded: u32) -> Color {
         }
     }
 
-    /// Returns `(alpha, red, green, blue)` encoded as u32
+    /// Returns `(alpha, red, green, blue)` encoded as u32 in the form `0xAARRGGBB`. This is the inverse
+    /// of [`Self::from_argb_encoded()`].
     pub fn as_argb_encoded(&self) -> u32 {
         ((self.red as u32) << 16)
             | ((self.green as u32) << 8)
@@ -113,6 +115,18 @@ pub fn as_argb_encoded(&self) -> u32 {
             | ((self.alpha as u32) << 24)
     }
 
+    /// Construct a color from an array of bytes in the order `[red, green, blue, alpha]`, such as
+    /// a pixel of an RGBA8 image.
+    pub const fn from_rgba_bytes([red, green, blue, alpha]: [u8; 4]) -> Self {
+        Self { red, green, blue, alpha }
+    }
+
+    /// Returns the color channels as an array of bytes in the order `[red, green, blue, alpha]`.
+    /// This is the inverse of [`Self::from_rgba_bytes()`].
+    pub const fn to_rgba_bytes(&self) -> [u8; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+    }
+
     /// Construct a color from the alpha, red, green and blue color channel parameters.
     pub const fn from_argb_u8(alpha: u8, red: u8, green: u8, blue: u8) -> Self {
         Self { red, green, blue, alpha }
@@ -404,6 +418,15 @@ fn test_rgb_to_hsv() {
     );
 }
 
+#[test]
+fn test_encoded_and_bytes_round_trip() {
+    let color = Color::from_argb_u8(0x12, 0x34, 0x56, 0x78);
+    assert_eq!(color.as_argb_encoded(), 0x12345678);
+    assert_eq!(Color::from_argb_encoded(0x12345678), color);
+    assert_eq!(color.to_rgba_bytes(), [0x34, 0x56, 0x78, 0x12]);
+    assert_eq!(Color::from_rgba_bytes([0x34, 0x56, 0x78, 0x12]), color);
+}
+
 #[test]
 fn test_brighter_darker() {
     let blue = Color::from_rgb_u8(0, 0, 128);
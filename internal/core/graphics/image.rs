@@ -592,6 +592,53 @@ pub fn load_from_path(path: &std::path::Path) -> Result<Self, LoadImageError> {
         })
     }
 
+    #[cfg(feature = "image-decoders")]
+    /// Creates a new Image by decoding the specified buffer, which contains the raw data of an
+    /// image file in one of the supported formats, such as PNG or JPEG.
+    ///
+    /// Use [`Self::load_from_svg_data`] for SVG data.
+    pub fn load_from_encoded_data(buffer: &[u8]) -> Result<Self, LoadImageError> {
+        let image = image::load_from_memory(buffer).map_err(|_| LoadImageError(()))?;
+        Ok(Image(ImageInner::EmbeddedImage {
+            cache_key: ImageCacheKey::Invalid,
+            buffer: self::cache::dynamic_image_to_shared_image_buffer(image),
+        }))
+    }
+
+    #[cfg(feature = "image-decoders")]
+    /// Decodes all frames of an animated image, such as an animated GIF, from the specified buffer.
+    /// Each frame is returned together with the duration it should be shown for. Frames are already
+    /// composited with the preceding frames according to their disposal method, so they can be
+    /// shown one after the other, for example by assigning them to the `source` of an `Image` element.
+    ///
+    /// Images that aren't animated are returned as a single frame with a zero duration.
+    pub fn load_frames_from_encoded_data(
+        buffer: &[u8],
+    ) -> Result<Vec<(core::time::Duration, Self)>, LoadImageError> {
+        use image::AnimationDecoder;
+
+        if image::guess_format(buffer).ok() != Some(image::ImageFormat::Gif) {
+            return Ok(vec![(Default::default(), Self::load_from_encoded_data(buffer)?)]);
+        }
+
+        let decoder = image::codecs::gif::GifDecoder::new(std::io::Cursor::new(buffer))
+            .map_err(|_| LoadImageError(()))?;
+        decoder
+            .into_frames()
+            .map(|frame| {
+                let frame = frame.map_err(|_| LoadImageError(()))?;
+                let duration = frame.delay().into();
+                let pixels = frame.into_buffer();
+                let buffer = SharedPixelBuffer::clone_from_slice(
+                    pixels.as_raw(),
+                    pixels.width(),
+                    pixels.height(),
+                );
+                Ok((duration, Self::from_rgba8(buffer)))
+            })
+            .collect()
+    }
+
     /// Creates a new Image from the specified shared pixel buffer, where each pixel has three color
     /// channels (red, green and blue) encoded as u8.
     pub fn from_rgb8(buffer: SharedPixelBuffer<Rgb8Pixel>) -> Self {
@@ -676,6 +723,68 @@ pub fn size(&self) -> IntSize {
         }
     }
 
+    /// Returns the average color of the image, for example to tint the surrounding user interface
+    /// to match it. Every pixel contributes in proportion to its alpha, so fully transparent pixels
+    /// are ignored. The returned color is opaque.
+    ///
+    /// Large images are sampled on a grid of at most 64 by 64 pixels. Returns `None` if the image is
+    /// fully transparent or its pixels are not accessible, such as for textures owned by the renderer.
+    pub fn average_color(&self) -> Option<super::Color> {
+        const MAX_SAMPLES_PER_AXIS: u32 = 64;
+
+        let buffer = self.0.render_to_buffer(None)?;
+        let (width, height) = (buffer.width(), buffer.height());
+        let step_x = (width / MAX_SAMPLES_PER_AXIS).max(1) as usize;
+        let step_y = (height / MAX_SAMPLES_PER_AXIS).max(1) as usize;
+
+        // Sums of the premultiplied color channels and of the alpha channel
+        let (mut red, mut green, mut blue, mut alpha) = (0u64, 0u64, 0u64, 0u64);
+        let mut add_pixels = |pixels: &mut dyn Iterator<Item = (u8, u8, u8, u8)>| {
+            for (r, g, b, a) in pixels {
+                red += r as u64;
+                green += g as u64;
+                blue += b as u64;
+                alpha += a as u64;
+            }
+        };
+        fn sample<Pixel: Copy>(
+            buffer: &SharedPixelBuffer<Pixel>,
+            step_x: usize,
+            step_y: usize,
+        ) -> impl Iterator<Item = Pixel> + '_ {
+            let stride = buffer.width() as usize;
+            buffer
+                .as_slice()
+                .chunks_exact(stride.max(1))
+                .step_by(step_y)
+                .flat_map(move |line| line.iter().step_by(step_x).copied())
+        }
+        match &buffer {
+            SharedImageBuffer::RGB8(buffer) => {
+                add_pixels(&mut sample(buffer, step_x, step_y).map(|p| (p.r, p.g, p.b, 255)))
+            }
+            SharedImageBuffer::RGBA8(buffer) => {
+                add_pixels(&mut sample(buffer, step_x, step_y).map(|p| {
+                    let premultiply = |c: u8| (c as u16 * p.a as u16 / 255) as u8;
+                    (premultiply(p.r), premultiply(p.g), premultiply(p.b), p.a)
+                }))
+            }
+            SharedImageBuffer::RGBA8Premultiplied(buffer) => {
+                add_pixels(&mut sample(buffer, step_x, step_y).map(|p| (p.r, p.g, p.b, p.a)))
+            }
+        }
+
+        if alpha == 0 {
+            return None;
+        }
+        let unpremultiply = |c: u64| ((c * 255 + alpha / 2) / alpha).min(255) as u8;
+        Some(super::Color::from_rgb_u8(
+            unpremultiply(red),
+            unpremultiply(green),
+            unpremultiply(blue),
+        ))
+    }
+
     #[cfg(feature = "std")]
     /// Returns the path of the image on disk, if it was constructed via [`Self::load_from_path`].
     ///
@@ -791,6 +900,72 @@ fn test_image_size_from_buffer_without_backend() {
     }
 }
 
+#[test]
+fn test_image_average_color() {
+    use super::Color;
+
+    assert_eq!(Image::default().average_color(), None);
+
+    let mut buffer = SharedPixelBuffer::<Rgb8Pixel>::new(100, 70);
+    buffer.make_mut_slice().fill(Rgb8Pixel::new(10, 200, 30));
+    assert_eq!(Image::from_rgb8(buffer).average_color(), Some(Color::from_rgb_u8(10, 200, 30)));
+
+    // Transparent padding around an opaque sprite doesn't darken the result
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(4, 4);
+    buffer.make_mut_slice()[5] = Rgba8Pixel::new(255, 0, 0, 255);
+    buffer.make_mut_slice()[6] = Rgba8Pixel::new(0, 0, 255, 255);
+    assert_eq!(Image::from_rgba8(buffer).average_color(), Some(Color::from_rgb_u8(128, 0, 128)));
+
+    let buffer = SharedPixelBuffer::<Rgba8Pixel>::new(4, 4);
+    assert_eq!(Image::from_rgba8(buffer).average_color(), None);
+}
+
+#[cfg(feature = "image-decoders")]
+#[test]
+fn test_image_from_encoded_data() {
+    let mut png = Vec::new();
+    image::RgbaImage::from_pixel(3, 2, image::Rgba([255, 0, 0, 128]))
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageOutputFormat::Png)
+        .unwrap();
+    let image = Image::load_from_encoded_data(&png).unwrap();
+    assert_eq!(image.size(), [3, 2].into());
+    match image.0 {
+        ImageInner::EmbeddedImage { buffer: SharedImageBuffer::RGBA8(buffer), .. } => {
+            assert_eq!(buffer.as_slice()[0], Rgba8Pixel::new(255, 0, 0, 128))
+        }
+        _ => panic!("expected an RGBA8 buffer"),
+    }
+
+    assert!(Image::load_from_encoded_data(b"AaBbCcDd").is_err());
+}
+
+#[cfg(feature = "image-decoders")]
+#[test]
+fn test_image_frames_from_encoded_data() {
+    let delay = image::Delay::from_numer_denom_ms(100, 1);
+    let mut gif = Vec::new();
+    image::codecs::gif::GifEncoder::new(&mut gif)
+        .encode_frames([
+            image::Frame::from_parts(image::RgbaImage::new(4, 4), 0, 0, delay),
+            image::Frame::from_parts(image::RgbaImage::new(4, 4), 0, 0, delay),
+        ])
+        .unwrap();
+    let frames = Image::load_frames_from_encoded_data(&gif).unwrap();
+    assert_eq!(frames.len(), 2);
+    for (duration, frame) in frames {
+        assert_eq!(duration, core::time::Duration::from_millis(100));
+        assert_eq!(frame.size(), [4, 4].into());
+    }
+
+    let mut png = Vec::new();
+    image::RgbImage::new(4, 4)
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageOutputFormat::Png)
+        .unwrap();
+    let frames = Image::load_frames_from_encoded_data(&png).unwrap();
+    assert_eq!(frames.len(), 1);
+    assert_eq!(frames[0].1.size(), [4, 4].into());
+}
+
 #[cfg(feature = "svg")]
 #[test]
 fn test_image_size_from_svg() {
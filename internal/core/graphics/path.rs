@@ -8,6 +8,7 @@
 use crate::items::PathEvent;
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
+use alloc::vec::Vec;
 use auto_enums::auto_enum;
 use const_field_offset::FieldOffsets;
 use i_slint_core_macros::*;
@@ -135,6 +136,32 @@ pub enum PathElement {
     Close,
 }
 
+impl PathElement {
+    /// Returns false if any of the coordinates is NaN or infinite, as these can't be rendered.
+    fn has_finite_coordinates(&self) -> bool {
+        let coordinates: &[f32] = match self {
+            PathElement::MoveTo(PathMoveTo { x, y }) => &[*x, *y],
+            PathElement::LineTo(PathLineTo { x, y }) => &[*x, *y],
+            PathElement::ArcTo(PathArcTo { x, y, radius_x, radius_y, x_rotation, .. }) => {
+                &[*x, *y, *radius_x, *radius_y, *x_rotation]
+            }
+            PathElement::CubicTo(PathCubicTo {
+                x,
+                y,
+                control_1_x,
+                control_1_y,
+                control_2_x,
+                control_2_y,
+            }) => &[*x, *y, *control_1_x, *control_1_y, *control_2_x, *control_2_y],
+            PathElement::QuadraticTo(PathQuadraticTo { x, y, control_x, control_y }) => {
+                &[*x, *y, *control_x, *control_y]
+            }
+            PathElement::Close => &[],
+        };
+        coordinates.iter().all(|c| c.is_finite())
+    }
+}
+
 struct ToLyonPathEventIterator<'a> {
     events_it: core::slice::Iter<'a, PathEvent>,
     coordinates_it: core::slice::Iter<'a, lyon_path::math::Point>,
@@ -284,6 +311,9 @@ fn default() -> Self {
 
 impl PathData {
     /// This function returns an iterator that allows traversing the path by means of lyon events.
+    ///
+    /// The coordinates must be finite, use [`Self::first_non_finite_coordinate()`] to check
+    /// that beforehand.
     pub fn iter(self) -> Option<PathDataIterator> {
         PathDataIterator {
             it: match self {
@@ -315,6 +345,22 @@ pub fn iter(self) -> Option<PathDataIterator> {
         .into()
     }
 
+    /// Returns the index and a description of the first element, or of the first coordinate for
+    /// [`PathData::Events`], that has a coordinate that is not a finite number. Such paths can't be rendered.
+    pub fn first_non_finite_coordinate(&self) -> Option<(usize, alloc::string::String)> {
+        match self {
+            PathData::Elements(elements) => elements
+                .iter()
+                .position(|e| !e.has_finite_coordinates())
+                .map(|index| (index, alloc::format!("{:?}", elements[index]))),
+            PathData::Events(_, coordinates) => coordinates
+                .iter()
+                .position(|p| !p.x.is_finite() || !p.y.is_finite())
+                .map(|index| (index, alloc::format!("{:?}", coordinates[index]))),
+            PathData::None | PathData::Commands(_) => None,
+        }
+    }
+
     fn build_path(element_it: core::slice::Iter<PathElement>) -> lyon_path::Path {
         use lyon_geom::SvgArc;
         use lyon_path::math::{Angle, Point, Vector};
@@ -386,6 +432,182 @@ fn build_path(element_it: core::slice::Iter<PathElement>) -> lyon_path::Path {
     }
 }
 
+/// Returns true if `point` lies inside the shape described by `events` when it's filled according
+/// to `fill_rule`. Curves are flattened with the given `tolerance`.
+///
+/// Pass the same events and fill rule that are used for rendering, so that the area that reacts
+/// to input matches the visible shape exactly.
+pub fn path_contains_point(
+    events: impl IntoIterator<Item = lyon_path::PathEvent>,
+    point: lyon_path::math::Point,
+    fill_rule: crate::items::FillRule,
+    tolerance: f32,
+) -> bool {
+    let fill_rule = match fill_rule {
+        crate::items::FillRule::Nonzero => lyon_path::FillRule::NonZero,
+        crate::items::FillRule::Evenodd => lyon_path::FillRule::EvenOdd,
+    };
+    lyon_algorithms::hit_test::hit_test_path(&point, events, fill_rule, tolerance)
+}
+
+/// Splits the outline described by `events` into dashes, following `pattern`, which alternates
+/// between the lengths of dashes and gaps. The lengths are in the same units as the coordinates
+/// of the events. `offset` specifies the distance into the pattern at the start of each sub-path.
+/// If `pattern` has an odd number of entries, it is repeated to yield an even number, like in SVG.
+///
+/// The curves of the path are flattened with the given `tolerance`. On closed sub-paths the
+/// pattern continues across the closing segment, so a dash that crosses the start point is emitted
+/// as one continuous line.
+///
+/// Returns None if the pattern is empty or invalid, in which case the outline should be solid.
+pub fn dash_path(
+    events: impl Iterator<Item = lyon_path::Event<lyon_path::math::Point, lyon_path::math::Point>>,
+    pattern: &[f32],
+    offset: f32,
+    tolerance: f32,
+) -> Option<lyon_path::Path> {
+    use lyon_path::iterator::PathIterator;
+
+    if pattern.iter().any(|length| !length.is_finite() || *length < 0.) {
+        return None;
+    }
+    let mut pattern = pattern.to_vec();
+    if pattern.len() % 2 == 1 {
+        pattern.extend_from_within(..);
+    }
+    let total: f32 = pattern.iter().sum();
+    if total <= 0. || !offset.is_finite() {
+        return None;
+    }
+
+    let mut dasher = Dasher {
+        pattern,
+        start: (0, 0.),
+        index: 0,
+        remaining: 0.,
+        current: Vec::new(),
+        current_is_first: false,
+        first_dash: None,
+        builder: lyon_path::Path::builder(),
+    };
+
+    let mut position = offset.rem_euclid(total);
+    while position >= dasher.pattern[dasher.start.0] {
+        position -= dasher.pattern[dasher.start.0];
+        dasher.start.0 = (dasher.start.0 + 1) % dasher.pattern.len();
+    }
+    dasher.start.1 = dasher.pattern[dasher.start.0] - position;
+
+    for event in events.flattened(tolerance) {
+        match event {
+            lyon_path::Event::Begin { at } => dasher.begin(at),
+            lyon_path::Event::Line { from, to } => dasher.line(from, to),
+            lyon_path::Event::End { last, first, close } => dasher.end(last, first, close),
+            lyon_path::Event::Quadratic { .. } | lyon_path::Event::Cubic { .. } => {
+                unreachable!("flattened paths only contain lines")
+            }
+        }
+    }
+
+    Some(dasher.builder.build())
+}
+
+struct Dasher {
+    pattern: Vec<f32>,
+    /// Index into the pattern and remaining length of that entry at the start of a sub-path
+    start: (usize, f32),
+    index: usize,
+    remaining: f32,
+    /// Points of the dash that is currently being walked
+    current: Vec<lyon_path::math::Point>,
+    /// True if `current` began at the start of the sub-path
+    current_is_first: bool,
+    /// The dash that began at the start of the sub-path, held back so that it can be joined
+    /// with the last dash if the sub-path is closed.
+    first_dash: Option<Vec<lyon_path::math::Point>>,
+    builder: lyon_path::path::Builder,
+}
+
+impl Dasher {
+    fn is_dash(&self) -> bool {
+        self.index % 2 == 0
+    }
+
+    fn begin(&mut self, at: lyon_path::math::Point) {
+        (self.index, self.remaining) = self.start;
+        self.current.clear();
+        self.first_dash = None;
+        self.current_is_first = self.is_dash();
+        if self.is_dash() {
+            self.current.push(at);
+        }
+    }
+
+    fn line(&mut self, from: lyon_path::math::Point, to: lyon_path::math::Point) {
+        let length = (to - from).length();
+        let mut travelled = 0.;
+        while length - travelled > self.remaining {
+            travelled += self.remaining;
+            self.current.push(from.lerp(to, travelled / length));
+            if self.is_dash() {
+                self.finish_dash();
+            }
+            self.index = (self.index + 1) % self.pattern.len();
+            self.remaining = self.pattern[self.index];
+        }
+        self.remaining -= length - travelled;
+        if self.is_dash() {
+            self.current.push(to);
+        }
+    }
+
+    fn end(&mut self, last: lyon_path::math::Point, first: lyon_path::math::Point, close: bool) {
+        if close {
+            self.line(last, first);
+        }
+        if self.is_dash() {
+            if close && self.current_is_first {
+                // The entire sub-path is a single dash
+                let points = core::mem::take(&mut self.current);
+                self.emit(&points, true);
+            } else if close && self.first_dash.is_some() {
+                // Continue the last dash into the first one
+                let mut points = core::mem::take(&mut self.current);
+                points.extend(self.first_dash.take().into_iter().flatten().skip(1));
+                self.emit(&points, false);
+            } else {
+                self.finish_dash();
+            }
+        }
+        if let Some(first_dash) = self.first_dash.take() {
+            self.emit(&first_dash, false);
+        }
+    }
+
+    fn finish_dash(&mut self) {
+        let points = core::mem::take(&mut self.current);
+        if self.current_is_first {
+            self.current_is_first = false;
+            self.first_dash = Some(points);
+        } else {
+            self.emit(&points, false);
+        }
+    }
+
+    fn emit(&mut self, points: &[lyon_path::math::Point], close: bool) {
+        if let [first, rest @ ..] = points {
+            if rest.is_empty() {
+                return;
+            }
+            self.builder.begin(*first);
+            for point in rest {
+                self.builder.line_to(*point);
+            }
+            self.builder.end(close);
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) mod ffi {
     #![allow(unsafe_code)]
@@ -427,3 +649,168 @@ pub(crate) mod ffi {
         core::ptr::write(out_coordinates as *mut crate::SharedVector<Point>, coordinates);
     }
 }
+
+#[test]
+fn test_path_with_non_finite_coordinates() {
+    let elements = crate::SharedVector::from_slice(&[
+        PathElement::MoveTo(PathMoveTo { x: 0., y: 0. }),
+        PathElement::LineTo(PathLineTo { x: f32::NAN, y: 10. }),
+        PathElement::LineTo(PathLineTo { x: 10., y: f32::INFINITY }),
+        PathElement::Close,
+    ]);
+    let path = PathData::Elements(elements);
+    assert_eq!(path.first_non_finite_coordinate().map(|(index, _)| index), Some(1));
+
+    let events =
+        crate::SharedVector::from_slice(&[PathEvent::Begin, PathEvent::Line, PathEvent::EndClosed]);
+    let coordinates = crate::SharedVector::from_slice(&[
+        lyon_path::math::point(0., 0.),
+        lyon_path::math::point(0., 0.),
+        lyon_path::math::point(f32::NAN, 0.),
+    ]);
+    let path = PathData::Events(events, coordinates);
+    assert_eq!(path.first_non_finite_coordinate().map(|(index, _)| index), Some(2));
+
+    let path = PathData::Elements(crate::SharedVector::from_slice(&[
+        PathElement::MoveTo(PathMoveTo { x: 0., y: 0. }),
+        PathElement::LineTo(PathLineTo { x: 10., y: 10. }),
+    ]));
+    assert_eq!(path.first_non_finite_coordinate(), None);
+}
+
+#[test]
+fn test_path_contains_point() {
+    use crate::items::FillRule;
+    use lyon_path::math::point;
+
+    // Two squares, one inside the other, both drawn clockwise.
+    let mut builder = lyon_path::Path::builder();
+    for (min, max) in [(0., 100.), (25., 75.)] {
+        builder.begin(point(min, min));
+        builder.line_to(point(max, min));
+        builder.line_to(point(max, max));
+        builder.line_to(point(min, max));
+        builder.end(true);
+    }
+    let path = builder.build();
+
+    let outer = point(10., 10.);
+    let inner = point(50., 50.);
+    let outside = point(150., 50.);
+
+    assert!(path_contains_point(&path, outer, FillRule::Nonzero, 0.1));
+    assert!(path_contains_point(&path, inner, FillRule::Nonzero, 0.1));
+    assert!(!path_contains_point(&path, outside, FillRule::Nonzero, 0.1));
+
+    assert!(path_contains_point(&path, outer, FillRule::Evenodd, 0.1));
+    assert!(!path_contains_point(&path, inner, FillRule::Evenodd, 0.1));
+    assert!(!path_contains_point(&path, outside, FillRule::Evenodd, 0.1));
+}
+
+#[test]
+fn test_dash_path() {
+    use lyon_path::math::point;
+
+    // Returns the points of each dash, rounded to integers, and whether the dash is closed.
+    let dashes = |path: &lyon_path::Path, pattern: &[f32], offset: f32| {
+        let dashed = dash_path(path.iter(), pattern, offset, 0.1).unwrap();
+        let mut dashes = alloc::vec::Vec::new();
+        let mut points = alloc::vec::Vec::new();
+        for event in dashed.iter() {
+            match event {
+                lyon_path::Event::Begin { at } => points = alloc::vec![at],
+                lyon_path::Event::Line { to, .. } => points.push(to),
+                lyon_path::Event::End { close, .. } => dashes.push((
+                    core::mem::take(&mut points)
+                        .into_iter()
+                        .map(|p| (p.x.round(), p.y.round()))
+                        .collect::<alloc::vec::Vec<_>>(),
+                    close,
+                )),
+                _ => unreachable!(),
+            }
+        }
+        dashes.sort_by(|a, b| a.0[0].partial_cmp(&b.0[0]).unwrap());
+        dashes
+    };
+    let open_dashes = |path: &lyon_path::Path, pattern: &[f32], offset: f32| {
+        dashes(path, pattern, offset)
+            .into_iter()
+            .map(|(points, close)| {
+                assert!(!close);
+                (points[0].0, points[points.len() - 1].0)
+            })
+            .collect::<alloc::vec::Vec<_>>()
+    };
+
+    let mut builder = lyon_path::Path::builder();
+    builder.begin(point(0., 0.));
+    builder.line_to(point(100., 0.));
+    builder.end(false);
+    let line = builder.build();
+
+    assert_eq!(
+        open_dashes(&line, &[10., 5.], 0.),
+        [(0., 10.), (15., 25.), (30., 40.), (45., 55.), (60., 70.), (75., 85.), (90., 100.)]
+    );
+    // The offset wraps around the length of the pattern, also when negative
+    let offset_dashes =
+        [(0., 5.), (10., 20.), (25., 35.), (40., 50.), (55., 65.), (70., 80.), (85., 95.)];
+    assert_eq!(open_dashes(&line, &[10., 5.], 20.), offset_dashes);
+    assert_eq!(open_dashes(&line, &[10., 5.], -10.), offset_dashes);
+    // Odd patterns are repeated
+    assert_eq!(
+        open_dashes(&line, &[10.], 0.),
+        [(0., 10.), (20., 30.), (40., 50.), (60., 70.), (80., 90.)]
+    );
+    assert_eq!(
+        open_dashes(&line, &[10., 5., 5.], 0.),
+        [
+            (0., 10.),
+            (15., 20.),
+            (30., 35.),
+            (40., 50.),
+            (55., 60.),
+            (70., 75.),
+            (80., 90.),
+            (95., 100.)
+        ]
+    );
+    // Zero-length gaps split dashes, zero-length dashes become dots
+    assert_eq!(
+        open_dashes(&line, &[10., 0., 5., 5.], 0.)[..3],
+        [(0., 10.), (10., 15.), (20., 30.)]
+    );
+    assert_eq!(
+        open_dashes(&line, &[0., 10.], 0.),
+        (0..10).map(|i| (i as f32 * 10., i as f32 * 10.)).collect::<alloc::vec::Vec<_>>()
+    );
+
+    // Invalid patterns and offsets
+    assert!(dash_path(line.iter(), &[], 0., 0.1).is_none());
+    assert!(dash_path(line.iter(), &[0., 0.], 0., 0.1).is_none());
+    assert!(dash_path(line.iter(), &[10., -5.], 0., 0.1).is_none());
+    assert!(dash_path(line.iter(), &[10., f32::NAN], 0., 0.1).is_none());
+    assert!(dash_path(line.iter(), &[10., 5.], f32::INFINITY, 0.1).is_none());
+
+    let mut builder = lyon_path::Path::builder();
+    builder.begin(point(0., 0.));
+    builder.line_to(point(100., 0.));
+    builder.line_to(point(100., 100.));
+    builder.line_to(point(0., 100.));
+    builder.end(true);
+    let rect = builder.build();
+
+    // The dash that crosses the start point is joined with the first one across the closing segment
+    let rect_dashes = dashes(&rect, &[30., 20.], 10.);
+    assert_eq!(rect_dashes.len(), 8);
+    assert!(rect_dashes.contains(&(alloc::vec![(0., 10.), (0., 0.), (20., 0.)], false)));
+    assert!(rect_dashes.contains(&(alloc::vec![(90., 0.), (100., 0.), (100., 20.)], false)));
+    assert!(rect_dashes.contains(&(alloc::vec![(100., 40.), (100., 70.)], false)));
+
+    // A dash that is longer than the outline covers it entirely
+    assert_eq!(
+        dashes(&rect, &[500., 10.], 0.),
+        [(alloc::vec![(0., 0.), (100., 0.), (100., 100.), (0., 100.), (0., 0.)], true)]
+    );
+}
@@ -377,7 +377,10 @@ fn combine_clip(
     /// Apply the opacity (between 0 and 1) for all following items until the next call to restore_state.
     fn apply_opacity(&mut self, opacity: f32);
 
+    /// Saves the clip, transform and opacity, so that they can be restored with restore_state.
     fn save_state(&mut self);
+    /// Restores the state saved with the matching call to save_state. Calling this more often
+    /// than save_state is a programming error.
     fn restore_state(&mut self);
 
     /// Returns the scale factor
@@ -7,7 +7,7 @@
 When adding an item or a property, it needs to be kept in sync with different place.
 Lookup the [`crate::items`] module documentation.
 */
-use super::{ImageFit, ImageRendering, Item, ItemConsts, ItemRc, RenderingResult};
+use super::{ImageFit, ImageRendering, ImageTiling, Item, ItemConsts, ItemRc, RenderingResult};
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
     KeyEventResult, MouseEvent,
@@ -129,6 +129,8 @@ pub struct ClippedImage {
     pub source_clip_y: Property<i32>,
     pub source_clip_width: Property<i32>,
     pub source_clip_height: Property<i32>,
+    pub horizontal_tiling: Property<ImageTiling>,
+    pub vertical_tiling: Property<ImageTiling>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
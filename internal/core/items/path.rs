@@ -38,6 +38,9 @@ pub struct Path {
     pub fill_rule: Property<FillRule>,
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
+    pub stroke_dash_length: Property<LogicalLength>,
+    pub stroke_dash_gap: Property<LogicalLength>,
+    pub stroke_dash_offset: Property<LogicalLength>,
     pub viewbox_x: Property<f32>,
     pub viewbox_y: Property<f32>,
     pub viewbox_width: Property<f32>,
@@ -127,11 +130,24 @@ impl Path {
     /// Returns an iterator of the events of the path and an offset, so that the
     /// shape fits into the width/height of the path while respecting the stroke
     /// width.
+    ///
+    /// Returns None for paths that can't be rendered. If a coordinate is not a finite number,
+    /// this is also reported with [`crate::debug_log!`], so renderers should cache the result
+    /// for as long as the properties of the path don't change.
     pub fn fitted_path_events(
         self: Pin<&Self>,
         self_rc: &ItemRc,
     ) -> Option<(LogicalVector, PathDataIterator)> {
-        let mut elements_iter = self.elements().iter()?;
+        let elements = self.elements();
+        if let Some((index, element)) = elements.first_non_finite_coordinate() {
+            crate::debug_log!(
+                "Error: element {index} ({element}) of the Path item with index {} at {:?} has a coordinate that is not a finite number, the path is not rendered",
+                self_rc.index(),
+                self_rc.map_to_window(Default::default()),
+            );
+            return None;
+        }
+        let mut elements_iter = elements.iter()?;
 
         let stroke_width = self.stroke_width();
         let geometry = self_rc.geometry();
@@ -155,6 +171,18 @@ pub fn fitted_path_events(
         elements_iter.fit(bounds_width.get() as _, bounds_height.get() as _, maybe_viewbox);
         (offset, elements_iter).into()
     }
+
+    /// Returns the dash pattern to use when stroking the path, as alternating lengths of dashes
+    /// and gaps in logical pixels, followed by the offset into the pattern. Returns None if
+    /// the outline is solid.
+    pub fn stroke_dash_pattern(self: Pin<&Self>) -> Option<([f32; 2], f32)> {
+        let dash: f32 = self.stroke_dash_length().get() as _;
+        let gap: f32 = self.stroke_dash_gap().get() as _;
+        if dash < 0. || gap <= 0. {
+            return None;
+        }
+        Some(([dash, gap], self.stroke_dash_offset().get() as _))
+    }
 }
 
 impl ItemConsts for Path {
@@ -101,6 +101,8 @@
 pub struct SlintContext {
     pub(crate) platform: alloc::boxed::Box<dyn Platform>,
     pub window_count: core::cell::RefCell<isize>,
+    /// True if `window_count` holds an extra count that keeps the event loop running when the last window is closed.
+    pub(crate) keep_event_loop_alive: core::cell::Cell<bool>,
 }
 
 /// Internal function to access the platform abstraction.
@@ -69,7 +69,7 @@ fn process_events(
     /// This is being phased out, see #1499.
     fn set_event_loop_quit_on_last_window_closed(&self, quit_on_last_window_closed: bool) {
         assert!(!quit_on_last_window_closed);
-        crate::GLOBAL_CONTEXT.with(|ctx| (*ctx.get().unwrap().window_count.borrow_mut()) += 1);
+        set_quit_on_last_window_closed(false);
     }
 
     /// Return an [`EventLoopProxy`] that can be used to send event to the event loop
@@ -121,7 +121,8 @@ fn clipboard_text(&self, _clipboard: Clipboard) -> Option<String> {
 
     /// This function is called when debug() is used in .slint files. The implementation
     /// should direct the output to some developer visible terminal. The default implementation
-    /// uses stderr if available, or `console.log` when targeting wasm.
+    /// uses stderr if available, or `console.log` when targeting wasm. With the `log` feature
+    /// enabled, the default implementation emits the output with `log::debug!()` instead.
     fn debug_log(&self, _arguments: core::fmt::Arguments) {
         crate::tests::default_debug_log(_arguments);
     }
@@ -201,13 +202,43 @@ pub fn set_platform(platform: Box<dyn Platform + 'static>) -> Result<(), SetPlat
             EVENTLOOP_PROXY.set(proxy).map_err(|_| SetPlatformError::AlreadySet)?
         }
         instance
-            .set(crate::SlintContext { platform, window_count: 0.into() })
+            .set(crate::SlintContext {
+                platform,
+                window_count: 0.into(),
+                keep_event_loop_alive: Default::default(),
+            })
             .map_err(|_| SetPlatformError::AlreadySet)
             .unwrap();
         Ok(())
     })
 }
 
+/// Changes whether the event loop quits when the last window is closed or hidden. This can be
+/// called while the event loop is running, for example to keep an application running in the
+/// system tray depending on its state.
+///
+/// If no window is visible when switching back to quitting on the last window closed, the event
+/// loop quits right away, as if the last window was just closed.
+pub fn set_quit_on_last_window_closed(quit_on_last_window_closed: bool) {
+    crate::GLOBAL_CONTEXT.with(|ctx| {
+        let Some(ctx) = ctx.get() else { return };
+        let keep_alive = !quit_on_last_window_closed;
+        if ctx.keep_event_loop_alive.replace(keep_alive) == keep_alive {
+            return;
+        }
+        let mut count = ctx.window_count.borrow_mut();
+        if keep_alive {
+            *count += 1;
+        } else {
+            *count -= 1;
+            if *count <= 0 {
+                drop(count);
+                let _ = crate::api::quit_event_loop();
+            }
+        }
+    })
+}
+
 /// Call this function to update and potentially activate any pending timers, as well
 /// as advance the state of any active animations.
 ///
@@ -2129,7 +2129,10 @@ fn save_state(&mut self) {
     }
 
     fn restore_state(&mut self) {
-        self.current_state = self.state_stack.pop().unwrap();
+        self.current_state = self
+            .state_stack
+            .pop()
+            .expect("internal error: restore_state() called without matching save_state()");
     }
 
     fn scale_factor(&self) -> f32 {
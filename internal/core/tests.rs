@@ -111,7 +111,9 @@ pub fn debug_log_impl(args: core::fmt::Arguments) {
 #[doc(hidden)]
 pub fn default_debug_log(_arguments: core::fmt::Arguments) {
     cfg_if::cfg_if! {
-        if #[cfg(target_arch = "wasm32")] {
+        if #[cfg(feature = "log")] {
+            log::debug!("{}", _arguments);
+        } else if #[cfg(target_arch = "wasm32")] {
             use wasm_bindgen::prelude::*;
 
             #[wasm_bindgen]
@@ -129,7 +131,7 @@ pub fn default_debug_log(_arguments: core::fmt::Arguments) {
 
 #[macro_export]
 /// This macro allows producing debug output that will appear on stderr in regular builds
-/// and in the console log for wasm builds.
+/// and in the console log for wasm builds, or as `log::debug!()` records when the `log` feature is enabled.
 macro_rules! debug_log {
     ($($t:tt)*) => ($crate::tests::debug_log_impl(format_args!($($t)*)))
 }
@@ -181,6 +181,36 @@ pub fn layout_lines<R>(
                 TextHorizontalAlignment::Right => self.max_width - text_width(),
             };
 
+            // The index in the line of the first glyph that is replaced with the elision glyph. Elision
+            // starts at the beginning of the grapheme that doesn't fit anymore, so that for example a
+            // combining mark is never separated from its base character.
+            let elide_index = if elide_long_line || elide_last_line {
+                let line_glyphs = &glyphs[line.glyph_range.clone()];
+                let mut glyph_x = Font::Length::zero();
+                line_glyphs
+                    .iter()
+                    .position(|glyph| {
+                        let overflows = x + glyph_x + glyph.advance > max_width_without_elision;
+                        glyph_x += glyph.advance;
+                        overflows
+                    })
+                    .or_else(|| elide_last_line.then(|| line_glyphs.len().saturating_sub(1)))
+                    .map(|index| {
+                        let byte_offset = |index: usize| {
+                            shape_buffer.absolute_byte_offset(line.glyph_range.start + index)
+                        };
+                        let grapheme_start =
+                            snap_to_grapheme_boundary(self.string, byte_offset(index), false);
+                        let mut index = index;
+                        while index > 0 && byte_offset(index - 1) >= grapheme_start {
+                            index -= 1;
+                        }
+                        index
+                    })
+            } else {
+                None
+            };
+
             let mut elide_glyph = elide_glyph.as_ref();
 
             let selection = selection
@@ -206,15 +236,10 @@ pub fn layout_lines<R>(
             let glyph_it = glyphs[line.glyph_range.clone()].iter();
             let mut glyph_x = Font::Length::zero();
             let mut positioned_glyph_it = glyph_it.enumerate().filter_map(|(index, glyph)| {
-                // TODO: cut off at grapheme boundaries
                 if glyph_x > self.max_width {
                     return None;
                 }
-                let elide_long_line = (elide_long_line || elide_last_line)
-                    && x + glyph_x + glyph.advance > max_width_without_elision;
-                let elide_last_line =
-                    elide_last_line && line.glyph_range.start + index == line.glyph_range.end - 1;
-                if elide_long_line || elide_last_line {
+                if elide_index.map_or(false, |elide_index| index >= elide_index) {
                     if let Some(elide_glyph) = elide_glyph.take() {
                         let x = glyph_x;
                         glyph_x += elide_glyph.advance;
@@ -306,6 +331,32 @@ pub fn cursor_pos_for_byte_offset(&self, byte_offset: usize) -> (Font::Length, F
         }
     }
 
+    /// Returns the horizontal extent and the y coordinate of the selected text on each line, for
+    /// drawing a selection highlight. Each area is as high as the font. For an empty selection,
+    /// the result is a single zero-width range at the cursor position.
+    pub fn selection_ranges(
+        &self,
+        selection: core::ops::Range<usize>,
+    ) -> Vec<(core::ops::Range<Font::Length>, Font::Length)> {
+        if selection.is_empty() {
+            let (x, y) = self.cursor_pos_for_byte_offset(selection.start);
+            return alloc::vec![(x..x, y)];
+        }
+
+        let mut ranges = Vec::new();
+        let _ = self.layout_lines::<()>(
+            |_, line_x, line_y, _, line_selection| {
+                if let Some(line_selection) = line_selection {
+                    ranges
+                        .push((line_x + line_selection.start..line_x + line_selection.end, line_y));
+                }
+                core::ops::ControlFlow::Continue(())
+            },
+            Some(selection),
+        );
+        ranges
+    }
+
     /// Returns the bytes offset for the given position
     pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Length)) -> usize {
         let mut byte_offset = 0;
@@ -318,7 +369,7 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
                     return core::ops::ControlFlow::Continue(());
                 }
 
-                if line.is_empty() {
+                if line.is_empty() || pos_x < line_x {
                     return core::ops::ControlFlow::Break(line.byte_range.start);
                 }
 
@@ -327,11 +378,17 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
                         && pos_x <= line_x + positioned_glyph.x + positioned_glyph.advance
                     {
                         if pos_x < line_x + positioned_glyph.x + positioned_glyph.advance / two {
-                            return core::ops::ControlFlow::Break(
+                            return core::ops::ControlFlow::Break(snap_to_grapheme_boundary(
+                                self.string,
                                 positioned_glyph.text_byte_offset,
-                            );
+                                false,
+                            ));
                         } else if let Some(next_glyph) = glyphs.next() {
-                            return core::ops::ControlFlow::Break(next_glyph.text_byte_offset);
+                            return core::ops::ControlFlow::Break(snap_to_grapheme_boundary(
+                                self.string,
+                                next_glyph.text_byte_offset,
+                                true,
+                            ));
                         }
                     }
                 }
@@ -346,6 +403,20 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
     }
 }
 
+/// Returns the given byte offset if it's at a grapheme boundary, otherwise the start of the grapheme
+/// it's in, or the start of the next grapheme if `forward` is true.
+fn snap_to_grapheme_boundary(text: &str, byte_offset: usize, forward: bool) -> usize {
+    let mut grapheme_cursor =
+        unicode_segmentation::GraphemeCursor::new(byte_offset, text.len(), true);
+    match grapheme_cursor.is_boundary(text, 0) {
+        Ok(false) if forward => {
+            grapheme_cursor.next_boundary(text, 0).ok().flatten().unwrap_or(text.len())
+        }
+        Ok(false) => grapheme_cursor.prev_boundary(text, 0).ok().flatten().unwrap_or(0),
+        _ => byte_offset,
+    }
+}
+
 #[test]
 fn test_no_linebreak_opportunity_at_eot() {
     let mut it = LineBreakIterator::new("Hello World");
@@ -456,6 +527,51 @@ fn test_elision() {
     debug_assert_eq!(rendered_text, "This is a lo…")
 }
 
+#[test]
+fn test_elision_at_grapheme_boundary() {
+    let font = FixedTestFont;
+    // The "ó" is made of an "o" followed by a combining acute accent, both shaped to separate glyphs
+    let text = "This is a lo\u{301}nger piece of text";
+
+    let mut lines = Vec::new();
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        max_width: 13. * 10.,
+        max_height: 10.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::NoWrap,
+        overflow: TextOverflow::Elide,
+        single_line: true,
+    };
+    paragraph
+        .layout_lines::<()>(
+            |glyphs, _, _, _, _| {
+                lines.push(
+                    glyphs
+                        .map(|positioned_glyph| positioned_glyph.glyph_id.clone())
+                        .collect::<Vec<_>>(),
+                );
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(lines.len(), 1);
+    let rendered_text = lines[0]
+        .iter()
+        .flat_map(|glyph_id| {
+            core::char::decode_utf16(core::iter::once(glyph_id.get()))
+                .map(|r| r.unwrap())
+                .collect::<Vec<char>>()
+        })
+        .collect::<String>();
+    debug_assert_eq!(rendered_text, "This is a l…")
+}
+
 #[test]
 fn test_exact_fit() {
     let font = FixedTestFont;
@@ -609,6 +725,52 @@ fn test_cursor_position_with_newline() {
     assert_eq!(paragraph.cursor_pos_for_byte_offset(5), (5. * 10., 0.));
 }
 
+#[test]
+fn test_selection_ranges() {
+    let font = FixedTestFont;
+    let text = "Hello World";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        max_width: 6. * 10.,
+        max_height: 20.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+    };
+
+    assert_eq!(paragraph.selection_ranges(3..8), vec![(30. ..50., 0.), (0. ..20., 10.)]);
+    assert_eq!(paragraph.selection_ranges(7..7), vec![(10. ..10., 10.)]);
+}
+
+#[test]
+fn test_byte_offset_at_grapheme_boundary() {
+    let font = FixedTestFont;
+    // The "ó" is made of an "o" followed by a combining acute accent, both shaped to separate glyphs
+    let text = "lo\u{301}ng";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        max_width: 10. * 10.,
+        max_height: 10.,
+        horizontal_alignment: TextHorizontalAlignment::Center,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::NoWrap,
+        overflow: TextOverflow::Clip,
+        single_line: true,
+    };
+
+    // The line starts at x = 25, the "o" ends at x = 45 and the accent at x = 55
+    assert_eq!(paragraph.byte_offset_for_position((5., 0.)), 0);
+    assert_eq!(paragraph.byte_offset_for_position((43., 0.)), 4);
+    assert_eq!(paragraph.byte_offset_for_position((47., 0.)), 1);
+    assert_eq!(paragraph.byte_offset_for_position((95., 0.)), text.len());
+}
+
 #[test]
 fn byte_offset_for_empty_line() {
     let font = FixedTestFont;
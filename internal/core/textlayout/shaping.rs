@@ -210,6 +210,17 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
 
         Self { glyphs, text_runs }
     }
+
+    /// Returns the byte offset in the entire text of the character that produced the glyph at `glyph_index`.
+    /// The offsets stored in the glyphs are relative to the start of their text run.
+    pub fn absolute_byte_offset(&self, glyph_index: usize) -> usize {
+        let run_start = self
+            .text_runs
+            .iter()
+            .find(|run| run.glyph_range.contains(&glyph_index))
+            .map_or(0, |run| run.byte_range.start);
+        run_start + self.glyphs[glyph_index].text_byte_offset
+    }
 }
 
 #[test]
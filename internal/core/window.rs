@@ -1026,6 +1026,11 @@ pub fn set_fullscreen(&self, enabled: bool) {
         self.update_window_properties()
     }
 
+    /// Returns true if the window is set to display fullscreen.
+    pub fn fullscreen(&self) -> bool {
+        self.fullscreen.get()
+    }
+
     /// Returns the upgraded window adapter
     pub fn window_adapter(&self) -> Rc<dyn WindowAdapter> {
         self.window_adapter_weak.upgrade().unwrap()
@@ -141,6 +141,12 @@ pub struct FontCache {
     loaded_font_coverage: HashMap<fontdb::ID, GlyphCoverage>,
     pub(crate) text_context: TextContext,
     available_families: HashSet<SharedString>,
+    // Families set by the application, tried in order before the system's fallback fonts.
+    fallback_families: Vec<SharedString>,
+    // The fallback families that are installed or registered, updated when fonts are registered.
+    registered_fallback_families: Vec<SharedString>,
+    // For a character and weight, the first of the registered fallback families that has a glyph for it.
+    fallback_family_for_char: HashMap<(char, fontdb::Weight), Option<SharedString>>,
 }
 
 impl Default for FontCache {
@@ -163,6 +169,9 @@ fn default() -> Self {
             loaded_font_coverage: HashMap::new(),
             text_context,
             available_families,
+            fallback_families: Vec::new(),
+            registered_fallback_families: Vec::new(),
+            fallback_family_for_char: HashMap::new(),
         }
     }
 }
@@ -280,12 +289,29 @@ pub fn font(
         //);
 
         let fallbacks = if !matches!(coverage_result, GlyphCoverageCheckResult::Complete) {
-            self.font_fallbacks_for_request(
-                font_request.family.as_ref(),
-                pixel_size,
-                &primary_font,
-                reference_text,
-            )
+            let missing_chars =
+                scripts_required.values().chain(chars_required.iter()).copied().collect::<Vec<_>>();
+            let mut configured_fallbacks = Vec::new();
+            for ch in missing_chars {
+                if let Some(family) = self.configured_fallback_for_char(ch, query) {
+                    if !configured_fallbacks.contains(&family) {
+                        configured_fallbacks.push(family);
+                    }
+                }
+            }
+            configured_fallbacks.sort_by_key(|family| {
+                self.registered_fallback_families.iter().position(|f| f == family)
+            });
+
+            configured_fallbacks
+                .into_iter()
+                .chain(self.font_fallbacks_for_request(
+                    font_request.family.as_ref(),
+                    pixel_size,
+                    &primary_font,
+                    reference_text,
+                ))
+                .collect()
         } else {
             Vec::new()
         };
@@ -459,6 +485,65 @@ fn font_fallbacks_for_request(
             .collect()
     }
 
+    pub fn set_fallback_families(&mut self, families: Vec<SharedString>) {
+        self.fallback_families = families;
+        self.update_registered_fallback_families();
+    }
+
+    /// Called after fonts were registered, as they may provide some of the fallback families.
+    pub fn update_registered_fallback_families(&mut self) {
+        // Unlike is_known_family(), this also sees fonts registered after the cache was created.
+        let registered_families = sharedfontdb::FONT_DB.with(|db| {
+            db.borrow()
+                .faces()
+                .flat_map(|face_info| face_info.families.iter().map(|(name, _)| name.clone()))
+                .collect::<HashSet<_>>()
+        });
+        self.registered_fallback_families = self
+            .fallback_families
+            .iter()
+            .filter(|family| registered_families.contains(family.as_str()))
+            .cloned()
+            .collect();
+        self.fallback_family_for_char.clear();
+    }
+
+    /// Returns the first of the registered fallback families that has a glyph for the character.
+    /// The result is cached, so that the families aren't searched again for the same character.
+    fn configured_fallback_for_char(
+        &mut self,
+        ch: char,
+        query: fontdb::Query<'_>,
+    ) -> Option<SharedString> {
+        if let Some(family) = self.fallback_family_for_char.get(&(ch, query.weight)) {
+            return family.clone();
+        }
+
+        use unicode_script::{Script, UnicodeScript};
+        let family = self.registered_fallback_families.clone().into_iter().find(|family| {
+            let fallback_font = self.load_single_font(Some(family), query);
+            let mut scripts_required = HashMap::new();
+            let mut chars_required = HashSet::new();
+            match ch.script() {
+                Script::Common | Script::Inherited | Script::Unknown => {
+                    chars_required.insert(ch);
+                }
+                script => {
+                    scripts_required.insert(script, ch);
+                }
+            }
+            self.check_and_update_script_coverage(
+                &mut scripts_required,
+                &mut chars_required,
+                fallback_font.fontdb_face_id,
+            );
+            scripts_required.is_empty() && chars_required.is_empty()
+        });
+
+        self.fallback_family_for_char.insert((ch, query.weight), family.clone());
+        family
+    }
+
     fn is_known_family(&self, family: &str) -> bool {
         self.available_families.contains(family)
     }
@@ -652,7 +737,19 @@ pub(crate) fn layout_text_lines(
                 for glyph in &text_metrics.glyphs {
                     current_x += glyph.advance_x;
                     if current_x >= w.get() {
-                        let txt = &line[..glyph.byte_index];
+                        // Don't separate for example a combining mark from its base character.
+                        let mut grapheme_cursor = unicode_segmentation::GraphemeCursor::new(
+                            glyph.byte_index,
+                            line.len(),
+                            true,
+                        );
+                        let cut = match grapheme_cursor.is_boundary(line, 0) {
+                            Ok(false) => {
+                                grapheme_cursor.prev_boundary(line, 0).ok().flatten().unwrap_or(0)
+                            }
+                            _ => glyph.byte_index,
+                        };
+                        let txt = &line[..cut];
                         if elide {
                             let elided = format!("{}…", txt);
                             process_line(&elided, y, start, &text_metrics);
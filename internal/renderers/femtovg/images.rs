@@ -7,9 +7,12 @@
 use i_slint_core::graphics::euclid;
 #[cfg(not(target_arch = "wasm32"))]
 use i_slint_core::graphics::BorrowedOpenGLTexture;
-use i_slint_core::graphics::{ImageCacheKey, IntSize, SharedImageBuffer};
+use i_slint_core::graphics::{
+    ImageCacheKey, IntRect, IntSize, Rgba8Pixel, SharedImageBuffer, SharedPixelBuffer,
+};
+use i_slint_core::items::{ImageRendering, ImageTiling};
 use i_slint_core::lengths::PhysicalPx;
-use i_slint_core::{items::ImageRendering, ImageInner};
+use i_slint_core::ImageInner;
 
 use super::itemrenderer::CanvasRc;
 
@@ -27,6 +30,13 @@ pub fn size(&self) -> Option<IntSize> {
             .ok()
     }
 
+    pub fn has_mipmaps(&self) -> bool {
+        self.canvas
+            .borrow()
+            .image_info(self.id)
+            .map_or(false, |info| info.flags().contains(femtovg::ImageFlags::GENERATE_MIPMAPS))
+    }
+
     pub fn as_render_target(&self) -> femtovg::RenderTarget {
         femtovg::RenderTarget::Image(self.id)
     }
@@ -89,6 +99,7 @@ pub fn new_from_image(
         canvas: &CanvasRc,
         target_size_for_scalable_source: Option<euclid::Size2D<u32, PhysicalPx>>,
         scaling: ImageRendering,
+        mipmaps: bool,
     ) -> Option<Rc<Self>> {
         let image_flags = match scaling {
             ImageRendering::Smooth => femtovg::ImageFlags::empty(),
@@ -147,14 +158,103 @@ pub fn new_from_image(
                     .unwrap()
             }
             _ => {
-                let buffer = image.render_to_buffer(target_size_for_scalable_source)?;
+                let buffer =
+                    premultiply_alpha(image.render_to_buffer(target_size_for_scalable_source)?);
                 let (image_source, flags) = image_buffer_to_image_source(&buffer);
-                canvas.borrow_mut().create_image(image_source, image_flags | flags).unwrap()
+                canvas
+                    .borrow_mut()
+                    .create_image(
+                        image_source,
+                        image_flags | flags | mipmap_flags(scaling, mipmaps),
+                    )
+                    .unwrap()
             }
         };
 
         Some(Self::adopt(canvas, image_id))
     }
+
+    // Upload the region of the image described by source_clip_rect (or the entire image if it's empty)
+    // into a texture that repeats along the tiled axes. The wrap mode is a property of the texture, so these
+    // textures are not shared via the TextureCache. femtovg doesn't support mirrored repeating, so along
+    // mirrored axes the texture contains the region followed by its mirror image.
+    pub fn new_tiled_from_image(
+        image: &ImageInner,
+        canvas: &CanvasRc,
+        source_clip_rect: IntRect,
+        (horizontal_tiling, vertical_tiling): (ImageTiling, ImageTiling),
+        scaling: ImageRendering,
+        mipmaps: bool,
+    ) -> Option<Rc<Self>> {
+        let mut image_flags = match scaling {
+            ImageRendering::Smooth => femtovg::ImageFlags::empty(),
+            ImageRendering::Pixelated => femtovg::ImageFlags::NEAREST,
+        } | mipmap_flags(scaling, mipmaps);
+        if horizontal_tiling != ImageTiling::None {
+            image_flags |= femtovg::ImageFlags::REPEAT_X;
+        }
+        if vertical_tiling != ImageTiling::None {
+            image_flags |= femtovg::ImageFlags::REPEAT_Y;
+        }
+
+        let mirror_x = horizontal_tiling == ImageTiling::Mirror;
+        let mirror_y = vertical_tiling == ImageTiling::Mirror;
+        let buffer = match premultiply_alpha(image.render_to_buffer(None)?) {
+            SharedImageBuffer::RGB8(buffer) => SharedImageBuffer::RGB8(crop_and_mirror(
+                &buffer,
+                source_clip_rect,
+                mirror_x,
+                mirror_y,
+            )?),
+            SharedImageBuffer::RGBA8(buffer) => SharedImageBuffer::RGBA8(crop_and_mirror(
+                &buffer,
+                source_clip_rect,
+                mirror_x,
+                mirror_y,
+            )?),
+            SharedImageBuffer::RGBA8Premultiplied(buffer) => SharedImageBuffer::RGBA8Premultiplied(
+                crop_and_mirror(&buffer, source_clip_rect, mirror_x, mirror_y)?,
+            ),
+        };
+
+        let (image_source, flags) = image_buffer_to_image_source(&buffer);
+        let image_id = canvas.borrow_mut().create_image(image_source, image_flags | flags).ok()?;
+        Some(Self::adopt(canvas, image_id))
+    }
+}
+
+/// Returns a copy of the clip_rect region of the buffer (or of the entire buffer if clip_rect is empty),
+/// followed by its mirror image on the mirrored axes.
+fn crop_and_mirror<Pixel: Clone + Default>(
+    buffer: &SharedPixelBuffer<Pixel>,
+    clip_rect: IntRect,
+    mirror_x: bool,
+    mirror_y: bool,
+) -> Option<SharedPixelBuffer<Pixel>> {
+    let buffer_rect = IntRect::from_size([buffer.width() as i32, buffer.height() as i32].into());
+    let clip_rect =
+        if clip_rect.is_empty() { buffer_rect } else { clip_rect.intersection(&buffer_rect)? };
+    if clip_rect.is_empty() {
+        return None;
+    }
+
+    let (width, height) = (clip_rect.width() as usize, clip_rect.height() as usize);
+    let target_width = if mirror_x { width * 2 } else { width };
+    let target_height = if mirror_y { height * 2 } else { height };
+
+    let source = buffer.as_slice();
+    let source_stride = buffer.width() as usize;
+    let mut target = SharedPixelBuffer::<Pixel>::new(target_width as u32, target_height as u32);
+    for (y, target_line) in target.make_mut_slice().chunks_mut(target_width).enumerate() {
+        let source_y = clip_rect.min_y() as usize + if y < height { y } else { 2 * height - 1 - y };
+        let source_line = &source[source_y * source_stride..][..source_stride];
+        for (x, pixel) in target_line.iter_mut().enumerate() {
+            let source_x =
+                clip_rect.min_x() as usize + if x < width { x } else { 2 * width - 1 - x };
+            *pixel = source_line[source_x].clone();
+        }
+    }
+    Some(target)
 }
 
 impl Drop for Texture {
@@ -168,6 +268,7 @@ pub struct TextureCacheKey {
     source_key: ImageCacheKey,
     target_size_for_scalable_source: Option<euclid::Size2D<u32, PhysicalPx>>,
     gpu_image_flags: ImageRendering,
+    mipmaps: bool,
 }
 
 impl TextureCacheKey {
@@ -175,11 +276,13 @@ pub fn new(
         resource: &ImageInner,
         target_size_for_scalable_source: Option<euclid::Size2D<u32, PhysicalPx>>,
         gpu_image_flags: ImageRendering,
+        mipmaps: bool,
     ) -> Option<Self> {
         ImageCacheKey::new(resource).map(|source_key| Self {
             source_key,
             target_size_for_scalable_source,
             gpu_image_flags,
+            mipmaps,
         })
     }
 }
@@ -228,6 +331,43 @@ pub(crate) fn clear(&mut self) {
     }
 }
 
+// Images drawn smaller than their size alias badly without mipmaps, for example photo thumbnails,
+// so let femtovg generate them for smoothly scaled images when the caller asks for them, which it
+// only does for images that are minified, as mipmaps take a third more memory. Each image has a
+// texture of its own, so the mipmaps can't bleed into neighboring images.
+fn mipmap_flags(scaling: ImageRendering, mipmaps: bool) -> femtovg::ImageFlags {
+    match scaling {
+        ImageRendering::Smooth if mipmaps => femtovg::ImageFlags::GENERATE_MIPMAPS,
+        _ => femtovg::ImageFlags::empty(),
+    }
+}
+
+// Texture filtering interpolates between neighboring texels before femtovg's shader premultiplies
+// the alpha, so with straight alpha the color of fully transparent texels bleeds into the edges
+// of semi-transparent images. Premultiply once before uploading instead. Opaque images are
+// uploaded unchanged.
+fn premultiply_alpha(buffer: SharedImageBuffer) -> SharedImageBuffer {
+    match buffer {
+        SharedImageBuffer::RGBA8(buffer)
+            if buffer.as_slice().iter().any(|pixel| pixel.a != 255) =>
+        {
+            let mut premultiplied =
+                SharedPixelBuffer::<Rgba8Pixel>::new(buffer.width(), buffer.height());
+            for (dst, src) in premultiplied.make_mut_slice().iter_mut().zip(buffer.as_slice()) {
+                let premultiply = |channel: u8| ((channel as u16 * src.a as u16 + 127) / 255) as u8;
+                *dst = Rgba8Pixel::new(
+                    premultiply(src.r),
+                    premultiply(src.g),
+                    premultiply(src.b),
+                    src.a,
+                );
+            }
+            SharedImageBuffer::RGBA8Premultiplied(premultiplied)
+        }
+        buffer => buffer,
+    }
+}
+
 fn image_buffer_to_image_source(
     buffer: &SharedImageBuffer,
 ) -> (femtovg::ImageSource<'_>, femtovg::ImageFlags) {
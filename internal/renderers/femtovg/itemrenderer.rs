@@ -13,8 +13,8 @@
 use i_slint_core::graphics::{Image, IntRect, Point, Size};
 use i_slint_core::item_rendering::{ItemCache, ItemRenderer};
 use i_slint_core::items::{
-    self, Clip, FillRule, ImageFit, ImageRendering, ItemRc, Layer, Opacity, RenderingResult,
-    TextHorizontalAlignment,
+    self, Clip, FillRule, ImageFit, ImageRendering, ImageTiling, ItemRc, Layer, Opacity,
+    RenderingResult, TextHorizontalAlignment,
 };
 use i_slint_core::lengths::{
     LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector, PointLengths,
@@ -56,6 +56,10 @@ fn is_colorized_image(&self) -> bool {
 }
 
 pub(super) type ItemGraphicsCache = ItemCache<Option<ItemGraphicsCacheEntry>>;
+/// Caches the logical offset of Path items, together with their path and the dashes of their
+/// outline in physical pixels.
+pub(super) type PathCache =
+    ItemCache<Option<(LogicalVector, Rc<femtovg::Path>, Option<Rc<femtovg::Path>>)>>;
 
 const KAPPA90: f32 = 0.55228;
 
@@ -68,7 +72,10 @@ struct State {
 
 pub struct GLItemRenderer<'a> {
     graphics_cache: &'a ItemGraphicsCache,
+    path_cache: &'a PathCache,
     texture_cache: &'a RefCell<super::images::TextureCache>,
+    /// True if the context can generate mipmaps for textures of any size.
+    mipmaps_supported: bool,
     box_shadow_cache: FemtovgBoxShadowCache,
     canvas: CanvasRc,
     // Layers that were scheduled for rendering where we can't delete the femtovg::ImageId yet
@@ -131,6 +138,86 @@ fn path_bounding_box(canvas: &CanvasRc, path: &femtovg::Path) -> euclid::default
     )
 }
 
+/// Converts the lyon path events into a femtovg path, scaled from logical to physical pixels.
+fn to_femtovg_path(
+    path_events: impl Iterator<Item = lyon_path::Event<Point, Point>>,
+    scale_factor: f32,
+) -> femtovg::Path {
+    let mut femtovg_path = femtovg::Path::new();
+
+    /// Contrary to the SVG spec, femtovg does not use the orientation of the path to
+    /// know if it needs to fill or not some part, it uses its own Solidity enum.
+    /// We must then compute ourself the orientation and set the Solidity accordingly.
+    #[derive(Default)]
+    struct OrientationCalculator {
+        area: f32,
+        prev: Point,
+    }
+
+    impl OrientationCalculator {
+        fn add_point(&mut self, p: Point) {
+            self.area += (p.x - self.prev.x) * (p.y + self.prev.y);
+            self.prev = p;
+        }
+    }
+
+    use femtovg::Solidity;
+
+    let mut orient = OrientationCalculator::default();
+
+    for x in path_events {
+        match x {
+            lyon_path::Event::Begin { at } => {
+                femtovg_path.solidity(if orient.area < 0. {
+                    Solidity::Hole
+                } else {
+                    Solidity::Solid
+                });
+                femtovg_path.move_to(at.x * scale_factor, at.y * scale_factor);
+                orient.area = 0.;
+                orient.prev = at;
+            }
+            lyon_path::Event::Line { from: _, to } => {
+                femtovg_path.line_to(to.x * scale_factor, to.y * scale_factor);
+                orient.add_point(to);
+            }
+            lyon_path::Event::Quadratic { from: _, ctrl, to } => {
+                femtovg_path.quad_to(
+                    ctrl.x * scale_factor,
+                    ctrl.y * scale_factor,
+                    to.x * scale_factor,
+                    to.y * scale_factor,
+                );
+                orient.add_point(to);
+            }
+
+            lyon_path::Event::Cubic { from: _, ctrl1, ctrl2, to } => {
+                femtovg_path.bezier_to(
+                    ctrl1.x * scale_factor,
+                    ctrl1.y * scale_factor,
+                    ctrl2.x * scale_factor,
+                    ctrl2.y * scale_factor,
+                    to.x * scale_factor,
+                    to.y * scale_factor,
+                );
+                orient.add_point(to);
+            }
+            lyon_path::Event::End { last: _, first: _, close } => {
+                femtovg_path.solidity(if orient.area < 0. {
+                    Solidity::Hole
+                } else {
+                    Solidity::Solid
+                });
+                if close {
+                    femtovg_path.close()
+                }
+            }
+        }
+    }
+
+    femtovg_path
+}
+
 // Return a femtovg::Path (in physical pixels) that represents the clip_rect, radius and border_width (all logical!)
 fn clip_path_for_rect_alike_item(
     clip_rect: LogicalRect,
@@ -280,6 +367,7 @@ fn draw_image(&mut self, image: Pin<&items::ImageItem>, item_rc: &ItemRc, _size:
             image.image_fit(),
             items::ImageItem::FIELD_OFFSETS.colorize.apply_pin(image),
             image.image_rendering(),
+            &|| (ImageTiling::None, ImageTiling::None, IntRect::default()),
         );
     }
 
@@ -303,6 +391,17 @@ fn draw_clipped_image(
             clipped_image.image_fit(),
             items::ClippedImage::FIELD_OFFSETS.colorize.apply_pin(clipped_image),
             clipped_image.image_rendering(),
+            &|| {
+                let source_clip_rect = IntRect::new(
+                    [clipped_image.source_clip_x(), clipped_image.source_clip_y()].into(),
+                    [clipped_image.source_clip_width(), clipped_image.source_clip_height()].into(),
+                );
+                (
+                    clipped_image.horizontal_tiling(),
+                    clipped_image.vertical_tiling(),
+                    source_clip_rect,
+                )
+            },
         );
     }
 
@@ -328,11 +427,25 @@ fn draw_text(&mut self, text: Pin<&items::Text>, _: &ItemRc, size: LogicalSize)
             )
         });
 
-        let paint = match self
-            .brush_to_paint(text.color(), &rect_to_path((size * self.scale_factor).into()))
-        {
-            Some(paint) => font.init_paint(text.letter_spacing() * self.scale_factor, paint),
-            None => return,
+        let text_path = rect_to_path((size * self.scale_factor).into());
+        let letter_spacing = text.letter_spacing() * self.scale_factor;
+        let fill_paint = self
+            .brush_to_paint(text.color(), &text_path)
+            .map(|paint| font.init_paint(letter_spacing, paint));
+
+        // The outline is stroked on top of the fill, centered on the glyph contours.
+        let stroke_width = text.stroke_width() * self.scale_factor;
+        let stroke_paint = if stroke_width.get() > 0. {
+            self.brush_to_paint(text.stroke(), &text_path).map(|mut paint| {
+                paint.set_line_width(stroke_width.get());
+                font.init_paint(letter_spacing, paint)
+            })
+        } else {
+            None
+        };
+
+        let Some(layout_paint) = fill_paint.as_ref().or(stroke_paint.as_ref()) else {
+            return;
         };
 
         let mut canvas = self.canvas.borrow_mut();
@@ -344,9 +457,14 @@ fn draw_text(&mut self, text: Pin<&items::Text>, _: &ItemRc, size: LogicalSize)
             text.wrap(),
             text.overflow(),
             false,
-            &paint,
+            layout_paint,
             |to_draw, pos, _, _| {
-                canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                if let Some(paint) = &fill_paint {
+                    canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), paint).unwrap();
+                }
+                if let Some(paint) = &stroke_paint {
+                    canvas.stroke_text(pos.x, pos.y, to_draw.trim_end(), paint).unwrap();
+                }
             },
         );
     }
@@ -546,84 +664,27 @@ fn draw_path(&mut self, path: Pin<&items::Path>, item_rc: &ItemRc, _size: Logica
             return;
         }
 
-        let (offset, path_events) = match path.fitted_path_events(item_rc) {
-            Some(offset_and_events) => offset_and_events,
-            None => return,
-        };
-
-        let mut femtovg_path = femtovg::Path::new();
-
-        /// Contrary to the SVG spec, femtovg does not use the orientation of the path to
-        /// know if it needs to fill or not some part, it uses its own Solidity enum.
-        /// We must then compute ourself the orientation and set the Solidity accordingly.
-        #[derive(Default)]
-        struct OrientationCalculator {
-            area: f32,
-            prev: Point,
-        }
-
-        impl OrientationCalculator {
-            fn add_point(&mut self, p: Point) {
-                self.area += (p.x - self.prev.x) * (p.y + self.prev.y);
-                self.prev = p;
-            }
-        }
-
-        use femtovg::Solidity;
-
-        let mut orient = OrientationCalculator::default();
-
-        for x in path_events.iter() {
-            match x {
-                lyon_path::Event::Begin { at } => {
-                    femtovg_path.solidity(if orient.area < 0. {
-                        Solidity::Hole
-                    } else {
-                        Solidity::Solid
-                    });
-                    femtovg_path
-                        .move_to(at.x * self.scale_factor.get(), at.y * self.scale_factor.get());
-                    orient.area = 0.;
-                    orient.prev = at;
-                }
-                lyon_path::Event::Line { from: _, to } => {
-                    femtovg_path
-                        .line_to(to.x * self.scale_factor.get(), to.y * self.scale_factor.get());
-                    orient.add_point(to);
-                }
-                lyon_path::Event::Quadratic { from: _, ctrl, to } => {
-                    femtovg_path.quad_to(
-                        ctrl.x * self.scale_factor.get(),
-                        ctrl.y * self.scale_factor.get(),
-                        to.x * self.scale_factor.get(),
-                        to.y * self.scale_factor.get(),
-                    );
-                    orient.add_point(to);
-                }
-
-                lyon_path::Event::Cubic { from: _, ctrl1, ctrl2, to } => {
-                    femtovg_path.bezier_to(
-                        ctrl1.x * self.scale_factor.get(),
-                        ctrl1.y * self.scale_factor.get(),
-                        ctrl2.x * self.scale_factor.get(),
-                        ctrl2.y * self.scale_factor.get(),
-                        to.x * self.scale_factor.get(),
-                        to.y * self.scale_factor.get(),
-                    );
-                    orient.add_point(to);
-                }
-                lyon_path::Event::End { last: _, first: _, close } => {
-                    femtovg_path.solidity(if orient.area < 0. {
-                        Solidity::Hole
-                    } else {
-                        Solidity::Solid
-                    });
-                    if close {
-                        femtovg_path.close()
-                    }
-                }
-            }
-        }
+        let scale_factor = self.scale_factor.get();
+        let (offset, femtovg_path, dashed_path) =
+            match self.path_cache.get_or_update_cache_entry(item_rc, || {
+                let (offset, path_events) = path.fitted_path_events(item_rc)?;
+                let femtovg_path = to_femtovg_path(path_events.iter(), scale_factor);
+                // femtovg doesn't support dashes, so stroke a path that consists of only the dashes instead.
+                let dashed_path = path.stroke_dash_pattern().and_then(|(pattern, dash_offset)| {
+                    let tolerance = 0.25 / scale_factor;
+                    let dashes = i_slint_core::graphics::dash_path(
+                        path_events.iter(),
+                        &pattern,
+                        dash_offset,
+                        tolerance,
+                    )?;
+                    Some(Rc::new(to_femtovg_path(dashes.iter(), scale_factor)))
+                });
+                Some((offset, Rc::new(femtovg_path), dashed_path))
+            }) {
+                Some(offset_and_paths) => offset_and_paths,
+                None => return,
+            };
 
         let fill_paint = self.brush_to_paint(path.fill(), &femtovg_path).map(|mut fill_paint| {
             fill_paint.set_fill_rule(match path.fill_rule() {
@@ -644,7 +705,7 @@ fn add_point(&mut self, p: Point) {
                 canvas.fill_path(&femtovg_path, fill_paint);
             }
             if let Some(border_paint) = &border_paint {
-                canvas.stroke_path(&femtovg_path, border_paint);
+                canvas.stroke_path(dashed_path.as_deref().unwrap_or(&*femtovg_path), border_paint);
             }
         })
     }
@@ -913,6 +974,10 @@ fn save_state(&mut self) {
     }
 
     fn restore_state(&mut self) {
+        debug_assert!(
+            self.state.len() > 1,
+            "internal error: restore_state() called without matching save_state()"
+        );
         self.state.pop();
         self.canvas.borrow_mut().restore();
     }
@@ -980,14 +1045,21 @@ fn draw_image_direct(&mut self, image: i_slint_core::graphics::Image) {
 
         let image_inner: &ImageInner = (&image).into();
 
-        let Some(cached_image) = TextureCacheKey::new(image_inner, None, Default::default())
+        // The image is drawn at its own size, so it doesn't need mipmaps.
+        let Some(cached_image) = TextureCacheKey::new(image_inner, None, Default::default(), false)
             .and_then(|cache_key| {
                 self.texture_cache.borrow_mut().lookup_image_in_cache_or_create(cache_key, || {
-                    Texture::new_from_image(image_inner, &self.canvas, None, Default::default())
+                    Texture::new_from_image(
+                        image_inner,
+                        &self.canvas,
+                        None,
+                        Default::default(),
+                        false,
+                    )
                 })
             })
             .or_else(|| {
-                Texture::new_from_image(image_inner, &self.canvas, None, Default::default())
+                Texture::new_from_image(image_inner, &self.canvas, None, Default::default(), false)
             })
         else {
             return;
@@ -1068,7 +1140,9 @@ impl<'a> GLItemRenderer<'a> {
     pub(super) fn new(
         canvas: &CanvasRc,
         graphics_cache: &'a ItemGraphicsCache,
+        path_cache: &'a PathCache,
         texture_cache: &'a RefCell<super::images::TextureCache>,
+        mipmaps_supported: bool,
         window: &'a i_slint_core::api::Window,
         width: u32,
         height: u32,
@@ -1076,7 +1150,9 @@ pub(super) fn new(
         let scale_factor = ScaleFactor::new(window.scale_factor());
         Self {
             graphics_cache,
+            path_cache,
             texture_cache,
+            mipmaps_supported,
             box_shadow_cache: Default::default(),
             canvas: canvas.clone(),
             layer_images_to_delete_after_flush: Default::default(),
@@ -1230,6 +1306,14 @@ fn colorize_image(
                 femtovg::ImageFlags::empty() | femtovg::ImageFlags::NEAREST
             }
         };
+        // Preserve the wrapping of tiled images
+        let repeat_flags = self
+            .canvas
+            .borrow()
+            .image_info(original_image.id)
+            .map_or(femtovg::ImageFlags::empty(), |info| {
+                info.flags() & (femtovg::ImageFlags::REPEAT_X | femtovg::ImageFlags::REPEAT_Y)
+            });
 
         let image_id = original_image.id;
         let colorized_image = self
@@ -1239,7 +1323,7 @@ fn colorize_image(
                 image_size.width as usize,
                 image_size.height as usize,
                 femtovg::PixelFormat::Rgba8,
-                femtovg::ImageFlags::PREMULTIPLIED | scaling_flags,
+                femtovg::ImageFlags::PREMULTIPLIED | scaling_flags | repeat_flags,
             )
             .expect("internal error allocating temporary texture for image colorization");
 
@@ -1296,6 +1380,9 @@ fn draw_image_impl(
         image_fit: ImageFit,
         colorize_property: Pin<&Property<Brush>>,
         image_rendering: ImageRendering,
+        // Returns the horizontal and vertical tiling, as well as the source clip rect. This is evaluated
+        // when creating the texture, as tiled textures are cropped to the clip rect.
+        tiling: &dyn Fn() -> (ImageTiling, ImageTiling, IntRect),
     ) {
         let target_w = target_width.get() * self.scale_factor;
         let target_h = target_height.get() * self.scale_factor;
@@ -1304,11 +1391,64 @@ fn draw_image_impl(
             return;
         }
 
+        let (horizontal_tiling, vertical_tiling, _) = tiling();
+        let tiled = (horizontal_tiling, vertical_tiling) != (ImageTiling::None, ImageTiling::None);
+
+        // Mipmaps take a third more texture memory and time to generate, so only request them for
+        // images that are drawn smaller than their size. They're only generated for textures uploaded
+        // from pixels: scalable images are rendered at the target size, and borrowed textures are used
+        // as they are.
+        let mipmaps = self.mipmaps_supported && image_rendering == ImageRendering::Smooth && {
+            let image = source_property.get();
+            let image_inner: &ImageInner = (&image).into();
+            let uploaded = !image_inner.is_svg();
+            #[cfg(not(target_arch = "wasm32"))]
+            let uploaded =
+                uploaded && !matches!(image_inner, ImageInner::BorrowedOpenGLTexture(..));
+            let source_size = if source_clip_rect.is_empty() {
+                image.size().cast::<f32>()
+            } else {
+                source_clip_rect.size.cast::<f32>()
+            };
+            let (scale_x, scale_y) =
+                (target_w.get() / source_size.width, target_h.get() / source_size.height);
+            let scale = if tiled {
+                self.scale_factor.get()
+            } else {
+                match image_fit {
+                    ImageFit::Fill | ImageFit::Contain => scale_x.min(scale_y),
+                    ImageFit::Cover => scale_x.max(scale_y),
+                }
+            };
+            uploaded && scale < 1.
+        };
+        // Recreate the texture if it was created without mipmaps and is now drawn smaller. Once it has
+        // mipmaps it's kept, so that an animation that scales across the image's size doesn't upload
+        // it again and again. This happens at most once per frame, in case a texture doesn't get
+        // mipmaps after all.
+        let mut mipmaps_checked = false;
+
         let cached_image = loop {
             let image_cache_entry = self.graphics_cache.get_or_update_cache_entry(item_rc, || {
                 let image = source_property.get();
                 let image_inner: &ImageInner = (&image).into();
 
+                let (horizontal_tiling, vertical_tiling, tiled_source_clip_rect) = tiling();
+                if (horizontal_tiling, vertical_tiling) != (ImageTiling::None, ImageTiling::None) {
+                    return Texture::new_tiled_from_image(
+                        image_inner,
+                        &self.canvas,
+                        tiled_source_clip_rect,
+                        (horizontal_tiling, vertical_tiling),
+                        image_rendering,
+                        mipmaps,
+                    )
+                    .map(ItemGraphicsCacheEntry::Texture)
+                    .map(|cache_entry| {
+                        self.colorize_image(cache_entry, colorize_property, image_rendering)
+                    });
+                }
+
                 let target_size_for_scalable_source = if image_inner.is_svg() {
                     let image_size = image.size();
                     if image_size.is_empty() {
@@ -1322,32 +1462,39 @@ fn draw_image_impl(
                     None
                 };
 
-                TextureCacheKey::new(image_inner, target_size_for_scalable_source, image_rendering)
-                    .and_then(|cache_key| {
-                        self.texture_cache.borrow_mut().lookup_image_in_cache_or_create(
-                            cache_key,
-                            || {
-                                Texture::new_from_image(
-                                    image_inner,
-                                    &self.canvas,
-                                    target_size_for_scalable_source,
-                                    image_rendering,
-                                )
-                            },
-                        )
-                    })
-                    .or_else(|| {
-                        Texture::new_from_image(
-                            image_inner,
-                            &self.canvas,
-                            target_size_for_scalable_source,
-                            image_rendering,
-                        )
-                    })
-                    .map(ItemGraphicsCacheEntry::Texture)
-                    .map(|cache_entry| {
-                        self.colorize_image(cache_entry, colorize_property, image_rendering)
-                    })
+                TextureCacheKey::new(
+                    image_inner,
+                    target_size_for_scalable_source,
+                    image_rendering,
+                    mipmaps,
+                )
+                .and_then(|cache_key| {
+                    self.texture_cache.borrow_mut().lookup_image_in_cache_or_create(
+                        cache_key,
+                        || {
+                            Texture::new_from_image(
+                                image_inner,
+                                &self.canvas,
+                                target_size_for_scalable_source,
+                                image_rendering,
+                                mipmaps,
+                            )
+                        },
+                    )
+                })
+                .or_else(|| {
+                    Texture::new_from_image(
+                        image_inner,
+                        &self.canvas,
+                        target_size_for_scalable_source,
+                        image_rendering,
+                        mipmaps,
+                    )
+                })
+                .map(ItemGraphicsCacheEntry::Texture)
+                .map(|cache_entry| {
+                    self.colorize_image(cache_entry, colorize_property, image_rendering)
+                })
             });
 
             // Check if the image in the cache is loaded. If not, don't draw any image and we'll return
@@ -1367,12 +1514,46 @@ fn draw_image_impl(
                 continue;
             }
 
+            if !mipmaps_checked
+                && !cached_image.is_colorized_image()
+                && mipmaps
+                && !cached_image.as_texture().has_mipmaps()
+            {
+                mipmaps_checked = true;
+                self.graphics_cache.release(item_rc);
+                continue;
+            }
+
             break cached_image.as_texture().clone();
         };
 
         let image_id = cached_image.id;
         let image_size = cached_image.size().unwrap_or_default().cast();
 
+        if tiled {
+            // The texture is already cropped to the source clip rect. It repeats at its natural size
+            // along tiled axes and is stretched along the others.
+            let pattern_width = if horizontal_tiling == ImageTiling::None {
+                target_w.get()
+            } else {
+                image_size.width * self.scale_factor.get()
+            };
+            let pattern_height = if vertical_tiling == ImageTiling::None {
+                target_h.get()
+            } else {
+                image_size.height * self.scale_factor.get()
+            };
+
+            let fill_paint =
+                femtovg::Paint::image(image_id, 0., 0., pattern_width, pattern_height, 0.0, 1.0)
+                    .with_anti_alias(false);
+
+            let mut path = femtovg::Path::new();
+            path.rect(0., 0., target_w.get(), target_h.get());
+            self.canvas.borrow_mut().fill_path(&path, &fill_paint);
+            return;
+        }
+
         let (source_width, source_height) = if source_clip_rect.is_empty() {
             (image_size.width, image_size.height)
         } else {
@@ -1465,6 +1646,13 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
 
                 let path_width = path_bounds.width();
                 let path_height = path_bounds.height();
+                let radius = (path_width + path_height) / 4.;
+
+                // A degenerate gradient has no extent to interpolate across, use the first stop.
+                if radius <= 0. {
+                    let first_stop = gradient.stops().next()?;
+                    return Some(femtovg::Paint::color(to_femtovg_color(&first_stop.color)));
+                }
 
                 let stops =
                     gradient.stops().map(|stop| (stop.position, to_femtovg_color(&stop.color)));
@@ -1472,7 +1660,7 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
                     path_width / 2.,
                     path_height / 2.,
                     0.,
-                    (path_width + path_height) / 4.,
+                    radius,
                     stops,
                 )
             }
@@ -20,7 +20,7 @@
 use i_slint_core::platform::PlatformError;
 use i_slint_core::renderer::RendererSealed;
 use i_slint_core::window::{WindowAdapter, WindowInner};
-use i_slint_core::Brush;
+use i_slint_core::{Brush, SharedString};
 
 type PhysicalLength = euclid::Length<f32, PhysicalPx>;
 type PhysicalRect = euclid::Rect<f32, PhysicalPx>;
@@ -32,12 +32,21 @@
 mod fonts;
 mod images;
 mod itemrenderer;
+#[cfg(target_arch = "wasm32")]
+mod webgl;
 
 /// This trait describes the interface GPU accelerated renderers in Slint require to render with OpenGL.
 ///
 /// It serves the purpose to ensure that the OpenGL context is current before running any OpenGL
 /// commands, as well as providing access to the OpenGL implementation by function pointers.
 ///
+/// The renderer doesn't create an OpenGL context itself. To render into a context that's managed by
+/// the application, for example one that's also used through `glow`, implement `ensure_current()` and
+/// `swap_buffers()` as no-ops if the application makes the context current and presents the frames.
+/// The renderer's shaders and textures are created in that context when calling [`FemtoVGRenderer::new()`],
+/// so it must be current at that point. Use [`FemtoVGRenderer::set_frame_synchronization()`] if other code
+/// that shares resources with the context depends on the rendering being complete.
+///
 /// # Safety
 ///
 /// This trait is unsafe because an implementation of get_proc_address could return dangling
@@ -62,6 +71,13 @@ fn resize(
     /// Returns the address of the OpenGL function specified by name, or a null pointer if the
     /// function does not exist.
     fn get_proc_address(&self, name: &std::ffi::CStr) -> *const std::ffi::c_void;
+    /// Returns the size of the surface in physical pixels, if the implementation can query it.
+    /// When this differs from the size of the window, for example while the windowing system is
+    /// still resizing the window, the renderer renders at the size of the surface so that the frame
+    /// matches the pixel grid of the surface. The default implementation returns `None`.
+    fn surface_size(&self) -> Option<i_slint_core::api::PhysicalSize> {
+        None
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -89,6 +105,43 @@ fn get_proc_address(&self, _: &std::ffi::CStr) -> *const std::ffi::c_void {
     }
 }
 
+/// Determines how the renderer waits for the OpenGL commands of a frame, before presenting it with
+/// [`OpenGLInterface::swap_buffers()`]. Used with [`FemtoVGRenderer::set_frame_synchronization()`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FrameSynchronization {
+    /// The commands are submitted to the driver, but the renderer doesn't wait for them.
+    #[default]
+    None,
+    /// Calls `glFlush()`, so that the commands are executed in finite time, for example before other
+    /// code issues commands to a context sharing resources with the renderer's context.
+    Flush,
+    /// Calls `glFinish()`, which blocks until the GPU has completed all commands. Use this when the
+    /// results must be available right after rendering, for example before reading a texture that's
+    /// shared with another context. This stalls the pipeline and reduces the frame rate.
+    Finish,
+}
+
+/// Describes what the OpenGL context of a [`FemtoVGRenderer`] supports, as returned by
+/// [`FemtoVGRenderer::capabilities()`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RendererCapabilities {
+    /// The largest width and height of a texture, in pixels.
+    pub max_texture_size: u32,
+    /// The maximum number of samples for multisample anti-aliasing of framebuffers, or 0 if
+    /// the context doesn't support multisampled framebuffers.
+    pub max_samples: u32,
+    /// The number of bits of the stencil buffer of the default framebuffer.
+    pub stencil_bits: u32,
+    /// The maximum degree of anisotropic texture filtering, or `None` if the context doesn't support it.
+    pub max_anisotropy: Option<f32>,
+    /// The names of the extensions supported by the context, sorted alphabetically. Use this to check
+    /// for other features, such as `GL_EXT_disjoint_timer_query` for timer queries or `GL_EXT_sRGB`.
+    /// In the browser, the names are those of the WebGL extensions, without the `GL_` prefix.
+    pub extensions: Vec<String>,
+}
+
 /// Use the FemtoVG renderer when implementing a custom Slint platform where you deliver events to
 /// Slint and want the scene to be rendered using OpenGL. The rendering is done using the [FemtoVG](https://github.com/femtovg/femtovg)
 /// library.
@@ -97,13 +150,28 @@ pub struct FemtoVGRenderer {
     rendering_notifier: RefCell<Option<Box<dyn RenderingNotifier>>>,
     canvas: CanvasRc,
     graphics_cache: itemrenderer::ItemGraphicsCache,
+    path_cache: itemrenderer::PathCache,
     texture_cache: RefCell<images::TextureCache>,
     rendering_metrics_collector: RefCell<Option<Rc<RenderingMetricsCollector>>>,
     rendering_first_time: Cell<bool>,
+    clear_background: Cell<bool>,
+    default_clear_color: Cell<Option<i_slint_core::Color>>,
+    /// The `GL_VERSION` and `GL_RENDERER` strings, queried when the renderer is created.
+    opengl_version_and_renderer: (String, String),
+    /// True if the context can generate mipmaps for textures with a size that's not a power of two.
+    mipmaps_supported: bool,
+    frame_synchronization: Cell<FrameSynchronization>,
+    frame_sink: RefCell<Option<Box<dyn FnMut(&[u8], u32, u32)>>>,
+    capabilities: RefCell<RendererCapabilities>,
+    // Used for frame synchronization and to check for OpenGL errors after rendering a frame in debug builds.
+    #[cfg(not(target_arch = "wasm32"))]
+    gl: glow::Context,
     // Last field, so that it's dropped last and context exists and is current when destroying the FemtoVG canvas
     opengl_context: Box<dyn OpenGLInterface>,
     #[cfg(target_arch = "wasm32")]
     canvas_id: String,
+    #[cfg(target_arch = "wasm32")]
+    context_loss_watcher: webgl::ContextLossWatcher,
 }
 
 impl FemtoVGRenderer {
@@ -122,7 +190,7 @@ pub fn new(
             femtovg::renderer::OpenGl::new_from_function_cstr(|name| {
                 opengl_context.get_proc_address(name)
             })
-            .unwrap()
+            .map_err(|e| format!("Error creating the FemtoVG OpenGL renderer: {e}"))?
         };
 
         #[cfg(target_arch = "wasm32")]
@@ -152,31 +220,61 @@ pub fn new(
             gl_renderer,
             self::fonts::FONT_CACHE.with(|cache| cache.borrow().text_context.clone()),
         )
-        .unwrap();
+        .map_err(|e| format!("Error creating the FemtoVG canvas: {e}"))?;
         let canvas = Rc::new(RefCell::new(femtovg_canvas));
 
+        // Safety: The context is current and the function pointers come from its implementation.
+        #[cfg(not(target_arch = "wasm32"))]
+        let gl = unsafe {
+            glow::Context::from_loader_function_cstr(|name| opengl_context.get_proc_address(name))
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let (opengl_version_and_renderer, capabilities) =
+            (query_version_and_renderer(&gl), query_capabilities(&gl));
+        #[cfg(target_arch = "wasm32")]
+        let (opengl_version_and_renderer, capabilities) = webgl_glow_context(&html_canvas)
+            .map(|gl| (query_version_and_renderer(&gl), query_capabilities(&gl)))
+            .unwrap_or_default();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let mipmaps_supported = supports_npot_mipmaps(&gl);
+        // WebGL 1 only supports mipmaps for textures with a size that's a power of two.
+        #[cfg(target_arch = "wasm32")]
+        let mipmaps_supported = false;
+
         Ok(Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
             canvas,
             graphics_cache: Default::default(),
+            path_cache: Default::default(),
             texture_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Cell::new(true),
+            clear_background: Cell::new(true),
+            default_clear_color: Default::default(),
+            opengl_version_and_renderer,
+            mipmaps_supported,
+            frame_synchronization: Default::default(),
+            frame_sink: Default::default(),
+            capabilities: RefCell::new(capabilities),
+            #[cfg(not(target_arch = "wasm32"))]
+            gl,
             opengl_context,
             #[cfg(target_arch = "wasm32")]
             canvas_id: html_canvas.id(),
+            #[cfg(target_arch = "wasm32")]
+            context_loss_watcher: webgl::ContextLossWatcher::new(html_canvas),
         })
     }
 
     /// Render the scene using OpenGL.
     pub fn render(&self) -> Result<(), i_slint_core::platform::PlatformError> {
-        self.internal_render_with_post_callback(
-            0.,
-            (0., 0.),
-            self.window_adapter()?.window().size(),
-            None,
-        )
+        let window_adapter = self.window_adapter()?;
+        let surface_size =
+            self.opengl_context.surface_size().unwrap_or_else(|| window_adapter.window().size());
+        self.internal_render_with_post_callback(0., (0., 0.), surface_size, None)
     }
 
     fn internal_render_with_post_callback(
@@ -188,6 +286,14 @@ fn internal_render_with_post_callback(
     ) -> Result<(), i_slint_core::platform::PlatformError> {
         self.opengl_context.ensure_current()?;
 
+        #[cfg(target_arch = "wasm32")]
+        if self.context_loss_watcher.take_restored() {
+            self.recreate_canvas_after_context_loss()?;
+        } else if self.context_loss_watcher.is_lost() {
+            // Nothing can be rendered until the browser restores the context.
+            return Ok(());
+        }
+
         if self.rendering_first_time.take() {
             *self.rendering_metrics_collector.borrow_mut() =
                 RenderingMetricsCollector::new("FemtoVG renderer");
@@ -225,14 +331,27 @@ fn internal_render_with_post_callback(
                     // We need to care about that `ceil()` when calculating metrics.
                     femtovg_canvas.set_size(surface_size.width, surface_size.height, scale);
 
-                    // Clear with window background if it is a solid color otherwise it will drawn as gradient
-                    if let Some(Brush::SolidColor(clear_color)) = window_background_brush {
+                    // Clear with window background if it is a solid color otherwise it will drawn as gradient.
+                    // Gradients are drawn on top of a fully transparent buffer, so that translucent windows
+                    // composite correctly with the desktop, unless a default clear color was set.
+                    let clear_color = match window_background_brush {
+                        Some(Brush::SolidColor(clear_color)) => {
+                            self::itemrenderer::to_femtovg_color(&clear_color)
+                        }
+                        _ => self
+                            .default_clear_color
+                            .get()
+                            .map_or(femtovg::Color::rgba(0, 0, 0, 0), |color| {
+                                self::itemrenderer::to_femtovg_color(&color)
+                            }),
+                    };
+                    if self.clear_background.get() {
                         femtovg_canvas.clear_rect(
                             0,
                             0,
                             surface_size.width,
                             surface_size.height,
-                            self::itemrenderer::to_femtovg_color(&clear_color),
+                            clear_color,
                         );
                     }
                 }
@@ -261,11 +380,14 @@ fn internal_render_with_post_callback(
                 }
 
                 self.graphics_cache.clear_cache_if_scale_factor_changed(window);
+                self.path_cache.clear_cache_if_scale_factor_changed(window);
 
                 let mut item_renderer = self::itemrenderer::GLItemRenderer::new(
                     &self.canvas,
                     &self.graphics_cache,
+                    &self.path_cache,
                     &self.texture_cache,
+                    self.mipmaps_supported,
                     window,
                     width.get(),
                     height.get(),
@@ -302,6 +424,9 @@ fn internal_render_with_post_callback(
 
                 self.canvas.borrow_mut().flush();
 
+                #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+                self.report_opengl_errors("rendering the frame");
+
                 // Delete any images and layer images (and their FBOs) before making the context not current anymore, to
                 // avoid GPU memory leaks.
                 self.texture_cache.borrow_mut().drain();
@@ -314,10 +439,125 @@ fn internal_render_with_post_callback(
             self.with_graphics_api(|api| callback.notify(RenderingState::AfterRendering, &api))?;
         }
 
+        if let Some(sink) = self.frame_sink.borrow_mut().as_mut() {
+            use rgb::ComponentBytes;
+            let frame = self
+                .canvas
+                .borrow_mut()
+                .screenshot()
+                .map_err(|e| format!("Error reading back the rendered frame: {e}"))?;
+            let (width, height) = (frame.width() as u32, frame.height() as u32);
+            sink(frame.buf().as_bytes(), width, height);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            use glow::HasContext;
+            // Safety: The context is current, and the commands don't refer to any resources.
+            match self.frame_synchronization.get() {
+                FrameSynchronization::None => {}
+                FrameSynchronization::Flush => unsafe { self.gl.flush() },
+                FrameSynchronization::Finish => unsafe { self.gl.finish() },
+            }
+        }
+
         self.opengl_context.swap_buffers()?;
         Ok(())
     }
 
+    /// Sets whether the back buffer is cleared with the window's background color before rendering a frame.
+    /// This is enabled by default.
+    ///
+    /// Disable clearing when the background is rendered by other means, for example by a rendering notifier
+    /// in [`RenderingState::BeforeRendering`]. Note that the content of the back buffer is usually undefined
+    /// after swapping buffers, unless the OpenGL context was created with a buffer preserving swap behavior.
+    /// When clearing is disabled, the window's background brush is only drawn if it is a gradient.
+    pub fn set_clear_background(&self, clear: bool) {
+        self.clear_background.set(clear);
+    }
+
+    /// Sets the color the back buffer is cleared with before rendering a frame, when the window doesn't
+    /// specify a solid background color itself, for example because its background is a gradient.
+    /// A solid `background` color of the window always takes precedence over this color.
+    ///
+    /// Pass `None` to clear with a fully transparent color, which is the default.
+    pub fn set_default_clear_color(&self, color: Option<i_slint_core::Color>) {
+        self.default_clear_color.set(color);
+    }
+
+    /// Sets how the renderer waits for the OpenGL commands of a frame before presenting it. The
+    /// default is [`FrameSynchronization::None`], which doesn't stall the pipeline.
+    ///
+    /// The synchronization happens after the [`RenderingState::AfterRendering`] notification, so it
+    /// also covers commands issued by the rendering notifier. This has no effect on wasm32.
+    pub fn set_frame_synchronization(&self, synchronization: FrameSynchronization) {
+        self.frame_synchronization.set(synchronization);
+    }
+
+    /// Sets a callback that receives every rendered frame, for example for screen recording or streaming.
+    /// Pass `None` to remove it. The frame is read back from the GPU after the
+    /// [`RenderingState::AfterRendering`] notification and before it is presented, so it also contains
+    /// what the rendering notifier drew. Reading back the frame stalls the pipeline, there's no cost when
+    /// no callback is set.
+    ///
+    /// The callback is invoked with the pixels, the width and the height of the frame. The pixels are
+    /// tightly packed RGBA with 8 bits per channel and premultiplied alpha, so each row is `width * 4`
+    /// bytes long. The rows are ordered from top to bottom.
+    pub fn set_frame_sink(&self, sink: Option<Box<dyn FnMut(&[u8], u32, u32)>>) {
+        *self.frame_sink.borrow_mut() = sink;
+    }
+
+    /// Sets the font families to use, in this order, for characters that the font requested by an element
+    /// doesn't cover, for example CJK characters in a Latin font. These families are tried before the
+    /// fallback fonts of the system. Families that aren't installed or registered are skipped.
+    ///
+    /// Fonts are shared between all FemtoVG renderers of a thread, so this applies to all of them. The
+    /// family chosen for a character is remembered, so repeated characters don't search the list again.
+    pub fn set_fallback_font_families(&self, families: impl IntoIterator<Item = SharedString>) {
+        self::fonts::FONT_CACHE
+            .with(|cache| cache.borrow_mut().set_fallback_families(families.into_iter().collect()));
+    }
+
+    /// Logs all pending OpenGL errors, with the operation that was performed before.
+    #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+    fn report_opengl_errors(&self, operation: &str) {
+        use glow::HasContext;
+
+        // The error flags are cleared one at a time. Bail out eventually, as some implementations keep
+        // reporting GL_CONTEXT_LOST.
+        for _ in 0..16 {
+            // Safety: Called while the context is current, after rendering.
+            let error = unsafe { self.gl.get_error() };
+            let name = match error {
+                glow::NO_ERROR => return,
+                glow::INVALID_ENUM => "GL_INVALID_ENUM",
+                glow::INVALID_VALUE => "GL_INVALID_VALUE",
+                glow::INVALID_OPERATION => "GL_INVALID_OPERATION",
+                glow::INVALID_FRAMEBUFFER_OPERATION => "GL_INVALID_FRAMEBUFFER_OPERATION",
+                glow::OUT_OF_MEMORY => "GL_OUT_OF_MEMORY",
+                glow::STACK_UNDERFLOW => "GL_STACK_UNDERFLOW",
+                glow::STACK_OVERFLOW => "GL_STACK_OVERFLOW",
+                glow::CONTEXT_LOST => "GL_CONTEXT_LOST",
+                _ => "unknown error",
+            };
+            i_slint_core::debug_log!("FemtoVG: OpenGL error {name} ({error:#x}) after {operation}");
+        }
+    }
+
+    /// Returns what the OpenGL context supports, such as the maximum texture size or the available
+    /// extensions, to enable features conditionally. The capabilities are queried when the renderer
+    /// is created, and in the browser again after a lost WebGL context was restored.
+    pub fn capabilities(&self) -> RendererCapabilities {
+        self.capabilities.borrow().clone()
+    }
+
+    /// Returns the `GL_VERSION` and `GL_RENDERER` strings reported by the OpenGL implementation used
+    /// for rendering. In the browser, the renderer string is usually a generic one, as the underlying
+    /// GL implementation is only exposed with an extension.
+    pub fn opengl_version_and_renderer(&self) -> Result<(String, String), PlatformError> {
+        Ok(self.opengl_version_and_renderer.clone())
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     fn with_graphics_api(
         &self,
@@ -348,6 +588,37 @@ fn with_graphics_api(
         Ok(())
     }
 
+    /// Replaces the FemtoVG canvas after the browser restored a lost WebGL context. All textures
+    /// and shaders of the old canvas are gone with the lost context, so the caches are cleared to
+    /// re-upload images, and the rendering notifier is informed to recreate its resources.
+    #[cfg(target_arch = "wasm32")]
+    fn recreate_canvas_after_context_loss(&self) -> Result<(), PlatformError> {
+        if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
+            self.with_graphics_api(|api| callback.notify(RenderingState::RenderingTeardown, &api))?;
+        }
+
+        self.graphics_cache.clear_all();
+        self.texture_cache.borrow_mut().clear();
+
+        let gl_renderer = femtovg::renderer::OpenGl::new_from_html_canvas(
+            self.context_loss_watcher.html_canvas(),
+        )
+        .map_err(|e| format!("Error re-creating the FemtoVG WebGL renderer: {e}"))?;
+        *self.canvas.borrow_mut() = femtovg::Canvas::new_with_text_context(
+            gl_renderer,
+            self::fonts::FONT_CACHE.with(|cache| cache.borrow().text_context.clone()),
+        )
+        .map_err(|e| format!("Error re-creating the FemtoVG canvas: {e}"))?;
+        *self.capabilities.borrow_mut() =
+            webgl_glow_context(self.context_loss_watcher.html_canvas())
+                .map(|gl| query_capabilities(&gl))
+                .unwrap_or_default();
+
+        // Sends RenderingSetup again when rendering the next frame.
+        self.rendering_first_time.set(true);
+        Ok(())
+    }
+
     fn window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
         self.maybe_window_adapter
             .borrow()
@@ -357,6 +628,78 @@ fn window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
     }
 }
 
+/// Queries the `GL_VERSION` and `GL_RENDERER` strings of the context, which must be current.
+fn query_version_and_renderer(gl: &glow::Context) -> (String, String) {
+    use glow::HasContext;
+    // Safety: This only queries the state of the current context.
+    unsafe { (gl.get_parameter_string(glow::VERSION), gl.get_parameter_string(glow::RENDERER)) }
+}
+
+/// Queries the capabilities of the context, which must be current.
+fn query_capabilities(gl: &glow::Context) -> RendererCapabilities {
+    use glow::HasContext;
+
+    // Safety: This only queries the state of the current context.
+    unsafe {
+        let version = gl.version();
+        let supported_extensions = gl.supported_extensions();
+        // Desktop and ES extension names have a GL_ prefix, WebGL extension names don't.
+        let has_extension = |name: &str| {
+            supported_extensions.contains(name)
+                || supported_extensions.contains(&format!("GL_{name}"))
+        };
+
+        let max_samples =
+            if version.major >= 3 { gl.get_parameter_i32(glow::MAX_SAMPLES) } else { 0 };
+        // GL_STENCIL_BITS was removed from the desktop core profile.
+        let stencil_bits = if !version.is_embedded && version.major >= 3 {
+            gl.get_framebuffer_attachment_parameter_i32(
+                glow::DRAW_FRAMEBUFFER,
+                glow::STENCIL,
+                glow::FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE,
+            )
+        } else {
+            gl.get_parameter_i32(glow::STENCIL_BITS)
+        };
+        let max_anisotropy = has_extension("EXT_texture_filter_anisotropic")
+            .then(|| gl.get_parameter_f32(glow::MAX_TEXTURE_MAX_ANISOTROPY_EXT));
+
+        let mut extensions = supported_extensions.iter().cloned().collect::<Vec<_>>();
+        extensions.sort();
+
+        RendererCapabilities {
+            max_texture_size: gl.get_parameter_i32(glow::MAX_TEXTURE_SIZE).max(0) as u32,
+            max_samples: max_samples.max(0) as u32,
+            stencil_bits: stencil_bits.max(0) as u32,
+            max_anisotropy,
+            extensions,
+        }
+    }
+}
+
+/// Returns true if the context can generate mipmaps for textures with a size that's not a power
+/// of two, which desktop OpenGL and OpenGL ES 3 can. OpenGL ES 2 needs an extension for that.
+#[cfg(not(target_arch = "wasm32"))]
+fn supports_npot_mipmaps(gl: &glow::Context) -> bool {
+    use glow::HasContext;
+    let version = gl.version();
+    !version.is_embedded
+        || version.major >= 3
+        || gl.supported_extensions().contains("GL_OES_texture_npot")
+}
+
+/// Returns a glow context for the WebGL context that femtovg created for the canvas.
+#[cfg(target_arch = "wasm32")]
+fn webgl_glow_context(html_canvas: &web_sys::HtmlCanvasElement) -> Option<glow::Context> {
+    use wasm_bindgen::JsCast;
+    let context = html_canvas
+        .get_context("webgl")
+        .ok()??
+        .dyn_into::<web_sys::WebGlRenderingContext>()
+        .ok()?;
+    Some(glow::Context::from_webgl1_context(context))
+}
+
 #[doc(hidden)]
 impl RendererSealed for FemtoVGRenderer {
     fn text_size(
@@ -487,14 +830,20 @@ fn register_font_from_memory(
         &self,
         data: &'static [u8],
     ) -> Result<(), Box<dyn std::error::Error>> {
-        sharedfontdb::register_font_from_memory(data)
+        sharedfontdb::register_font_from_memory(data)?;
+        self::fonts::FONT_CACHE
+            .with(|cache| cache.borrow_mut().update_registered_fallback_families());
+        Ok(())
     }
 
     fn register_font_from_path(
         &self,
         path: &std::path::Path,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        sharedfontdb::register_font_from_path(path)
+        sharedfontdb::register_font_from_path(path)?;
+        self::fonts::FONT_CACHE
+            .with(|cache| cache.borrow_mut().update_registered_fallback_families());
+        Ok(())
     }
 
     fn default_font_size(&self) -> LogicalLength {
@@ -520,11 +869,14 @@ fn free_graphics_resources(
     ) -> Result<(), i_slint_core::platform::PlatformError> {
         self.opengl_context.ensure_current()?;
         self.graphics_cache.component_destroyed(component);
+        self.path_cache.component_destroyed(component);
         Ok(())
     }
 
     fn set_window_adapter(&self, window_adapter: &Rc<dyn WindowAdapter>) {
         *self.maybe_window_adapter.borrow_mut() = Some(Rc::downgrade(window_adapter));
+        #[cfg(target_arch = "wasm32")]
+        self.context_loss_watcher.set_window_adapter(window_adapter);
         if self.opengl_context.ensure_current().is_ok() {
             self.graphics_cache.clear_all();
             self.texture_cache.borrow_mut().clear();
@@ -0,0 +1,104 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Tracks the loss and restoration of the WebGL context of the canvas element, so that the
+//! renderer can recreate its GPU resources after the browser restored the context.
+
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use i_slint_core::window::WindowAdapter;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+#[derive(Default)]
+struct ContextState {
+    lost: Cell<bool>,
+    restored: Cell<bool>,
+    window_adapter: RefCell<Option<Weak<dyn WindowAdapter>>>,
+}
+
+pub struct ContextLossWatcher {
+    html_canvas: web_sys::HtmlCanvasElement,
+    state: Rc<ContextState>,
+    on_lost: Closure<dyn FnMut(web_sys::Event)>,
+    on_restored: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+impl ContextLossWatcher {
+    pub fn new(html_canvas: web_sys::HtmlCanvasElement) -> Self {
+        let state = Rc::new(ContextState::default());
+
+        let on_lost = Closure::<dyn FnMut(web_sys::Event)>::new({
+            let state = state.clone();
+            move |event: web_sys::Event| {
+                // The browser only attempts to restore the context if the default action is prevented.
+                event.prevent_default();
+                state.lost.set(true);
+            }
+        });
+
+        let on_restored = Closure::<dyn FnMut(web_sys::Event)>::new({
+            let state = state.clone();
+            move |_: web_sys::Event| {
+                state.restored.set(true);
+                let window_adapter =
+                    state.window_adapter.borrow().as_ref().and_then(|w| w.upgrade());
+                if let Some(window_adapter) = window_adapter {
+                    window_adapter.request_redraw();
+                }
+            }
+        });
+
+        html_canvas
+            .add_event_listener_with_callback("webglcontextlost", on_lost.as_ref().unchecked_ref())
+            .ok();
+        html_canvas
+            .add_event_listener_with_callback(
+                "webglcontextrestored",
+                on_restored.as_ref().unchecked_ref(),
+            )
+            .ok();
+
+        Self { html_canvas, state, on_lost, on_restored }
+    }
+
+    pub fn set_window_adapter(&self, window_adapter: &Rc<dyn WindowAdapter>) {
+        *self.state.window_adapter.borrow_mut() = Some(Rc::downgrade(window_adapter));
+    }
+
+    pub fn html_canvas(&self) -> &web_sys::HtmlCanvasElement {
+        &self.html_canvas
+    }
+
+    /// Returns true if the context is lost and wasn't restored yet. Nothing can be rendered in this state.
+    pub fn is_lost(&self) -> bool {
+        self.state.lost.get() && !self.state.restored.get()
+    }
+
+    /// Returns true once after the context was restored, when all GPU resources need to be recreated.
+    pub fn take_restored(&self) -> bool {
+        let restored = self.state.restored.take();
+        if restored {
+            self.state.lost.set(false);
+        }
+        restored
+    }
+}
+
+impl Drop for ContextLossWatcher {
+    fn drop(&mut self) {
+        self.html_canvas
+            .remove_event_listener_with_callback(
+                "webglcontextlost",
+                self.on_lost.as_ref().unchecked_ref(),
+            )
+            .ok();
+        self.html_canvas
+            .remove_event_listener_with_callback(
+                "webglcontextrestored",
+                self.on_restored.as_ref().unchecked_ref(),
+            )
+            .ok();
+    }
+}
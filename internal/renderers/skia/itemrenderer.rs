@@ -8,7 +8,9 @@
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self, Vector2D};
 use i_slint_core::item_rendering::{ItemCache, ItemRenderer};
-use i_slint_core::items::{ImageFit, ImageRendering, ItemRc, Layer, Opacity, RenderingResult};
+use i_slint_core::items::{
+    ImageFit, ImageRendering, ImageTiling, ItemRc, Layer, Opacity, RenderingResult,
+};
 use i_slint_core::lengths::{
     LogicalLength, LogicalPoint, LogicalPx, LogicalRect, LogicalSize, LogicalVector, PhysicalPx,
     RectLengths, ScaleFactor, SizeLengths,
@@ -27,6 +29,8 @@ pub struct SkiaItemRenderer<'a> {
     pub canvas: &'a skia_safe::Canvas,
     pub scale_factor: ScaleFactor,
     pub window: &'a i_slint_core::api::Window,
+    /// The maximum level of anisotropic filtering for smoothly rendered images, if enabled.
+    pub max_anisotropy: Option<u8>,
     state_stack: Vec<RenderState>,
     current_state: RenderState,
     image_cache: &'a ItemCache<Option<skia_safe::Image>>,
@@ -46,6 +50,7 @@ pub fn new(
             canvas,
             scale_factor: ScaleFactor::new(window.scale_factor()),
             window,
+            max_anisotropy: None,
             state_stack: vec![],
             current_state: RenderState { alpha: 1.0 },
             image_cache,
@@ -98,6 +103,13 @@ fn brush_to_shader(
                 let (colors, pos): (Vec<_>, Vec<_>) =
                     g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
                 let circle_scale = width.max(height) / 2.;
+                // A degenerate gradient has no extent to interpolate across, use the first stop.
+                if circle_scale <= PhysicalLength::zero() {
+                    return g
+                        .stops()
+                        .next()
+                        .map(|s| skia_safe::shaders::color(to_skia_color(&s.color)));
+                }
                 skia_safe::gradient_shader::radial(
                     skia_safe::Point::new(0., 0.),
                     1.,
@@ -147,6 +159,29 @@ fn colorize_image(
         Some(surface.image_snapshot())
     }
 
+    /// Returns a copy of the given region of the image, or the image itself if no region is specified.
+    fn crop_image(
+        &mut self,
+        image: skia_safe::Image,
+        rect: Option<skia_safe::Rect>,
+    ) -> Option<skia_safe::Image> {
+        let Some(rect) = rect.map(|r| r.round()) else { return Some(image) };
+        if rect == image.bounds() {
+            return Some(image);
+        }
+
+        let image_info = skia_safe::ImageInfo::new(
+            rect.size(),
+            skia_safe::ColorType::RGBA8888,
+            skia_safe::AlphaType::Premul,
+            None,
+        );
+
+        let mut surface = self.canvas.new_surface(&image_info, None)?;
+        surface.canvas().draw_image(image, (-rect.left as f32, -rect.top as f32), None);
+        Some(surface.image_snapshot())
+    }
+
     fn draw_image_impl(
         &mut self,
         item_rc: &ItemRc,
@@ -158,6 +193,9 @@ fn draw_image_impl(
         image_fit: ImageFit,
         rendering: ImageRendering,
         colorize_property: Pin<&Property<Brush>>,
+        // Returns the horizontal and vertical tiling, as well as the source clip rect. This is evaluated
+        // when creating the cached image, as tiled images are cropped to the clip rect.
+        tiling: &dyn Fn() -> (ImageTiling, ImageTiling, Option<skia_safe::Rect>),
     ) {
         // TODO: avoid doing creating an SkImage multiple times when the same source is used in multiple image elements
         let skia_image = self.image_cache.get_or_update_cache_entry(item_rc, || {
@@ -169,6 +207,13 @@ fn draw_image_impl(
                 self.scale_factor,
                 self.canvas,
             )
+            .and_then(|skia_image| match tiling() {
+                (ImageTiling::None, ImageTiling::None, _) => Some(skia_image),
+                // The shader repeats the entire image, so tiled images need to be cropped to the clip rect.
+                (_, _, source_clip_rect) => {
+                    self.crop_image(skia_image, source_clip_rect.filter(|r| !r.is_empty()))
+                }
+            })
             .and_then(|skia_image| {
                 let brush = colorize_property.get();
                 if !brush.is_transparent() {
@@ -184,6 +229,54 @@ fn draw_image_impl(
             None => return,
         };
 
+        // Sample from mipmaps when smoothly scaling, so that images drawn much smaller than their size don't alias.
+        let filter_mode = match (rendering, self.max_anisotropy) {
+            (ImageRendering::Smooth, Some(max_anisotropy)) => {
+                skia_safe::sampling_options::SamplingOptions::from_aniso(max_anisotropy.into())
+            }
+            (ImageRendering::Smooth, None) => skia_safe::sampling_options::SamplingOptions::new(
+                skia_safe::sampling_options::FilterMode::Linear,
+                skia_safe::sampling_options::MipmapMode::Linear,
+            ),
+            (ImageRendering::Pixelated, _) => {
+                skia_safe::sampling_options::FilterMode::Nearest.into()
+            }
+        };
+
+        let (horizontal_tiling, vertical_tiling, _) = tiling();
+        if (horizontal_tiling, vertical_tiling) != (ImageTiling::None, ImageTiling::None) {
+            // Tiled images repeat at their natural size along tiled axes and are stretched along the others.
+            let to_tile_mode = |tiling| match tiling {
+                ImageTiling::None => skia_safe::TileMode::Clamp,
+                ImageTiling::Repeat => skia_safe::TileMode::Repeat,
+                ImageTiling::Mirror => skia_safe::TileMode::Mirror,
+            };
+            let scale_x = if horizontal_tiling == ImageTiling::None {
+                dest_rect.width() / skia_image.width() as f32
+            } else {
+                self.scale_factor.get()
+            };
+            let scale_y = if vertical_tiling == ImageTiling::None {
+                dest_rect.height() / skia_image.height() as f32
+            } else {
+                self.scale_factor.get()
+            };
+            let local_matrix = skia_safe::Matrix::scale((scale_x, scale_y))
+                .post_translate((dest_rect.min_x(), dest_rect.min_y()))
+                .to_owned();
+
+            if let Some(shader) = skia_image.to_shader(
+                (to_tile_mode(horizontal_tiling), to_tile_mode(vertical_tiling)),
+                filter_mode,
+                &local_matrix,
+            ) {
+                let mut paint = skia_safe::Paint::default();
+                paint.set_shader(shader);
+                self.canvas.draw_rect(to_skia_rect(&dest_rect), &paint);
+            }
+            return;
+        }
+
         self.canvas.save();
 
         let mut source_rect = source_rect.filter(|r| !r.is_empty()).unwrap_or_else(|| {
@@ -198,12 +291,6 @@ fn draw_image_impl(
                 .unwrap_or_default();
         self.canvas.concat(&transform);
 
-        let filter_mode: skia_safe::sampling_options::SamplingOptions = match rendering {
-            ImageRendering::Smooth => skia_safe::sampling_options::FilterMode::Linear,
-            ImageRendering::Pixelated => skia_safe::sampling_options::FilterMode::Nearest,
-        }
-        .into();
-
         self.canvas.draw_image_with_sampling_options(
             skia_image,
             skia_safe::Point::default(),
@@ -261,6 +348,7 @@ fn render_layer(
                 self.path_cache,
                 self.box_shadow_cache,
             );
+            sub_renderer.max_anisotropy = self.max_anisotropy;
 
             i_slint_core::item_rendering::render_item_children(
                 &mut sub_renderer,
@@ -413,6 +501,7 @@ fn draw_image(
             image.image_fit(),
             image.image_rendering(),
             items::ImageItem::FIELD_OFFSETS.colorize.apply_pin(image),
+            &|| (ImageTiling::None, ImageTiling::None, None),
         );
     }
 
@@ -444,6 +533,15 @@ fn draw_clipped_image(
             image.image_fit(),
             image.image_rendering(),
             items::ClippedImage::FIELD_OFFSETS.colorize.apply_pin(image),
+            &|| {
+                let source_rect = skia_safe::Rect::from_xywh(
+                    image.source_clip_x() as _,
+                    image.source_clip_y() as _,
+                    image.source_clip_width() as _,
+                    image.source_clip_height() as _,
+                );
+                (image.horizontal_tiling(), image.vertical_tiling(), Some(source_rect))
+            },
         );
     }
 
@@ -464,29 +562,40 @@ fn draw_text(
         let string = string.as_str();
         let font_request = text.font_request(WindowInner::from_pub(self.window));
 
-        let paint = match self.brush_to_paint(text.color(), max_width, max_height) {
-            Some(paint) => paint,
-            None => return,
+        let fill_paint = self.brush_to_paint(text.color(), max_width, max_height);
+
+        // The outline is stroked on top of the fill, centered on the glyph contours.
+        let stroke_width = text.stroke_width() * self.scale_factor;
+        let stroke_paint = if stroke_width.get() > 0. {
+            self.brush_to_paint(text.stroke(), max_width, max_height).map(|mut paint| {
+                paint.set_style(skia_safe::PaintStyle::Stroke);
+                paint.set_stroke_width(stroke_width.get());
+                paint
+            })
+        } else {
+            None
         };
 
-        let mut text_style = skia_safe::textlayout::TextStyle::new();
-        text_style.set_foreground_paint(&paint);
+        for paint in [fill_paint, stroke_paint].into_iter().flatten() {
+            let mut text_style = skia_safe::textlayout::TextStyle::new();
+            text_style.set_foreground_paint(&paint);
 
-        let (layout, layout_top_left) = super::textlayout::create_layout(
-            font_request,
-            self.scale_factor,
-            string,
-            Some(text_style),
-            Some(max_width),
-            max_height,
-            text.horizontal_alignment(),
-            text.vertical_alignment(),
-            text.wrap(),
-            text.overflow(),
-            None,
-        );
+            let (layout, layout_top_left) = super::textlayout::create_layout(
+                font_request.clone(),
+                self.scale_factor,
+                string,
+                Some(text_style),
+                Some(max_width),
+                max_height,
+                text.horizontal_alignment(),
+                text.vertical_alignment(),
+                text.wrap(),
+                text.overflow(),
+                None,
+            );
 
-        layout.paint(&mut self.canvas, to_skia_point(layout_top_left));
+            layout.paint(&mut self.canvas, to_skia_point(layout_top_left));
+        }
     }
 
     fn draw_text_input(
@@ -645,6 +754,13 @@ fn draw_path(
             border_paint.set_anti_alias(true);
             border_paint.set_stroke_width((path.stroke_width() * self.scale_factor).get());
             border_paint.set_stroke(true);
+            if let Some((pattern, dash_offset)) = path.stroke_dash_pattern() {
+                let intervals = pattern.map(|length| length * self.scale_factor.get());
+                border_paint.set_path_effect(skia_safe::PathEffect::dash(
+                    &intervals,
+                    dash_offset * self.scale_factor.get(),
+                ));
+            }
             self.canvas.draw_path(&skpath, &border_paint);
         }
     }
@@ -766,7 +882,10 @@ fn save_state(&mut self) {
     }
 
     fn restore_state(&mut self) {
-        self.current_state = self.state_stack.pop().unwrap();
+        self.current_state = self
+            .state_stack
+            .pop()
+            .expect("internal error: restore_state() called without matching save_state()");
         self.canvas.restore();
     }
 
@@ -97,6 +97,8 @@ pub struct SkiaRenderer {
         size: PhysicalWindowSize,
     ) -> Result<Box<dyn Surface>, PlatformError>,
     pre_present_callback: RefCell<Option<Box<dyn FnMut()>>>,
+    max_anisotropy: Cell<Option<u8>>,
+    color_matrix: Cell<Option<[f32; 20]>>,
 }
 
 impl Default for SkiaRenderer {
@@ -111,6 +113,8 @@ fn default() -> Self {
             surface: Default::default(),
             surface_factory: create_default_surface,
             pre_present_callback: Default::default(),
+            max_anisotropy: Default::default(),
+            color_matrix: Default::default(),
         }
     }
 }
@@ -132,6 +136,8 @@ pub fn default_software() -> Self {
                     .map(|r| Box::new(r) as Box<dyn Surface>)
             },
             pre_present_callback: Default::default(),
+            max_anisotropy: Default::default(),
+            color_matrix: Default::default(),
         }
     }
 
@@ -158,6 +164,8 @@ pub fn new_with_surface(surface: Box<dyn Surface + 'static>) -> Self {
                 Err("Skia renderer constructed with surface does not support dynamic surface re-creation".into())
             },
             pre_present_callback: Default::default(),
+            max_anisotropy: Default::default(),
+            color_matrix: Default::default(),
         }
     }
 
@@ -225,11 +233,33 @@ fn internal_render_with_post_callback(
                     let window_background_brush =
                         window_inner.window_item().map(|w| w.as_pin_ref().background());
 
-                    // Clear with window background if it is a solid color otherwise it will drawn as gradient
-                    if let Some(Brush::SolidColor(clear_color)) = window_background_brush {
-                        skia_canvas.clear(itemrenderer::to_skia_color(&clear_color));
+                    // Clear with window background if it is a solid color otherwise it will drawn as gradient.
+                    // Gradients are drawn on top of a fully transparent buffer, so that translucent windows
+                    // composite correctly with the desktop.
+                    let clear_color = match window_background_brush {
+                        Some(Brush::SolidColor(clear_color)) => {
+                            itemrenderer::to_skia_color(&clear_color)
+                        }
+                        _ => skia_safe::Color::TRANSPARENT,
+                    };
+
+                    // The color matrix is applied when compositing a layer that covers the entire frame,
+                    // including the background.
+                    let color_matrix = self.color_matrix.get();
+                    if let Some(color_matrix) = color_matrix {
+                        skia_canvas.clear(skia_safe::Color::TRANSPARENT);
+                        let mut layer_paint = skia_safe::Paint::default();
+                        layer_paint.set_color_filter(skia_safe::color_filters::matrix_row_major(
+                            &color_matrix,
+                            None,
+                        ));
+                        skia_canvas.save_layer(
+                            &skia_safe::canvas::SaveLayerRec::default().paint(&layer_paint),
+                        );
                     }
 
+                    skia_canvas.clear(clear_color);
+
                     if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
                         // For the BeforeRendering rendering notifier callback it's important that this happens *after* clearing
                         // the back buffer, in order to allow the callback to provide its own rendering of the background.
@@ -255,6 +285,7 @@ fn internal_render_with_post_callback(
                         &self.path_cache,
                         &mut box_shadow_cache,
                     );
+                    item_renderer.max_anisotropy = self.max_anisotropy.get();
 
                     // Draws the window background as gradient
                     match window_background_brush {
@@ -288,6 +319,10 @@ fn internal_render_with_post_callback(
 
                     drop(item_renderer);
 
+                    if color_matrix.is_some() {
+                        skia_canvas.restore();
+                    }
+
                     if let Some(ctx) = gr_context.as_mut() {
                         ctx.flush(None);
                     }
@@ -316,6 +351,35 @@ fn window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
     pub fn set_pre_present_callback(&self, callback: Option<Box<dyn FnMut()>>) {
         *self.pre_present_callback.borrow_mut() = callback;
     }
+
+    /// Enables anisotropic filtering up to the specified level for images rendered with smooth
+    /// `image-rendering`. This keeps images sharp that are scaled down more along one axis than the
+    /// other, at some cost of performance. Skia clamps the level to the maximum supported by the GPU,
+    /// and falls back to the default trilinear filtering when rendering in software or when the GPU
+    /// doesn't support anisotropic filtering. Pass `None` to disable it, which is the default.
+    pub fn set_max_anisotropy(&self, max_anisotropy: Option<u8>) {
+        self.max_anisotropy.set(max_anisotropy.filter(|level| *level > 1));
+    }
+
+    /// Sets a color matrix that's applied to every rendered frame as a whole, for example to show the
+    /// user interface in grayscale or with inverted colors for accessibility. The matrix has 4 rows of 5
+    /// values in row-major order and transforms the un-premultiplied red, green, blue and alpha components
+    /// in the range 0 to 1, with the fifth column as offset. Keep the last row at `[0, 0, 0, 1, 0]` to leave
+    /// the alpha channel untouched.
+    ///
+    /// For example, this matrix renders in grayscale:
+    /// ```text
+    /// [0.2126, 0.7152, 0.0722, 0., 0.,
+    ///  0.2126, 0.7152, 0.0722, 0., 0.,
+    ///  0.2126, 0.7152, 0.0722, 0., 0.,
+    ///  0.,     0.,     0.,     1., 0.]
+    /// ```
+    ///
+    /// The frame is rendered into an intermediate layer while a matrix is set, so pass `None` to
+    /// render directly again, which is the default.
+    pub fn set_color_matrix(&self, color_matrix: Option<[f32; 20]>) {
+        self.color_matrix.set(color_matrix);
+    }
 }
 
 impl i_slint_core::renderer::RendererSealed for SkiaRenderer {
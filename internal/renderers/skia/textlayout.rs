@@ -85,6 +85,23 @@ pub fn create_layout(
         },
     ));
 
+    // Variable fonts are matched with their default instance, so select the requested weight on the
+    // weight axis. Fonts without this axis ignore it, and values outside of its range are clamped.
+    let weight_coordinates = font_request.weight.map(|weight| {
+        [skia_safe::font_arguments::variation_position::Coordinate {
+            axis: skia_safe::FourByteTag::from_chars('w', 'g', 'h', 't'),
+            value: weight as f32,
+        }]
+    });
+    let font_arguments = weight_coordinates.as_ref().map(|coordinates| {
+        skia_safe::FontArguments::new().set_variation_design_position(
+            skia_safe::font_arguments::VariationPosition { coordinates },
+        )
+    });
+    if let Some(font_arguments) = &font_arguments {
+        text_style.set_font_arguments(font_arguments);
+    }
+
     let mut style = skia_safe::textlayout::ParagraphStyle::new();
 
     if overflow == items::TextOverflow::Elide {